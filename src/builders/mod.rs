@@ -1,5 +1,9 @@
 pub mod dataflow_identifier_builder;
 pub mod datakey_builder;
+pub mod observation_writer_builder;
+pub mod rate_limiter_builder;
+pub mod retry_policy_builder;
+pub mod sdmx_client_builder;
 pub mod sdmx_data_request_builder;
 pub mod sdmx_meta_request_builder;
 pub mod url_builder;
@@ -0,0 +1,46 @@
+use crate::exporters::observation_writer::ObservationWriter;
+
+pub struct ObservationWriterBuilder {
+    labels: bool,
+    attributes: Option<Box<[Box<str>]>>,
+    delimiter: u8,
+}
+
+impl ObservationWriterBuilder {
+    pub fn new() -> Self {
+        Self {
+            labels: false,
+            attributes: None,
+            delimiter: b',',
+        }
+    }
+
+    /// Adds a `{ID}_LABEL` column after each dimension and attribute code column.
+    pub fn labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// The attributes to write, in this order. Every attribute is written when not set.
+    /// Writing fails when one of them is not in the structure of the data.
+    pub fn attributes(mut self, attributes: &[&str]) -> Self {
+        self.attributes = Some(attributes.iter().map(|id| (*id).into()).collect());
+        self
+    }
+
+    /// The CSV field delimiter, `,` by default.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    pub fn build(self) -> ObservationWriter {
+        ObservationWriter::new(self.labels, self.attributes, self.delimiter)
+    }
+}
+
+impl Default for ObservationWriterBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{error_code::ErrorCode, models::typed::rate_limiter::RateLimiter, result::Result};
+
+pub struct RateLimiterBuilder {
+    requests_per_second: Option<f64>,
+    burst: u32,
+    max_in_flight: Option<usize>,
+}
+
+impl RateLimiterBuilder {
+    pub fn new() -> Self {
+        Self {
+            requests_per_second: None,
+            burst: 1,
+            max_in_flight: None,
+        }
+    }
+
+    pub fn requests_per_second(mut self, requests_per_second: f64) -> Self {
+        self.requests_per_second = Some(requests_per_second);
+        self
+    }
+
+    /// The number of requests that may be sent back to back before the rate applies.
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst;
+        self
+    }
+
+    pub fn max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.max_in_flight = Some(max_in_flight);
+        self
+    }
+
+    /// Fails when the rate is not a finite number greater than zero.
+    pub fn build(self) -> Result<RateLimiter> {
+        if let Some(rate) = self.requests_per_second {
+            if !rate.is_finite() || rate <= 0.0 {
+                return Err(ErrorCode::InvalidRequestsPerSecond(rate));
+            }
+        }
+
+        Ok(RateLimiter::new(
+            self.requests_per_second,
+            self.burst,
+            self.max_in_flight,
+        ))
+    }
+}
+
+impl Default for RateLimiterBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,157 @@
+use std::{sync::Arc, time::Duration};
+
+use url::Url;
+
+use crate::{
+    config::Config,
+    models::typed::{
+        protocol_version::ProtocolVersion, rate_limiter::RateLimiter, retry_policy::RetryPolicy,
+        sdmx_client::SdmxClient,
+    },
+    result::Result,
+    transport::{http_cache::HttpCache, reqwest_transport::ReqwestTransport, Transport},
+};
+
+pub struct SdmxClientBuilder {
+    base_url: Box<str>,
+    headers: Vec<(Box<str>, Box<str>)>,
+    key: Option<Box<str>>,
+    inner: reqwest::ClientBuilder,
+    transport: Option<Arc<dyn Transport>>,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    cache: Option<HttpCache>,
+    protocol_version: ProtocolVersion,
+}
+
+impl SdmxClientBuilder {
+    pub fn new() -> Self {
+        Self {
+            base_url: Config::BASE_URL.into(),
+            headers: vec![(
+                Config::HEADER_USER_AGENT_KEY.into(),
+                Config::HEADER_USER_AGENT_VALUE.into(),
+            )],
+            key: None,
+            inner: reqwest::Client::builder(),
+            transport: None,
+            retry_policy: RetryPolicy::none(),
+            rate_limiter: None,
+            cache: None,
+            protocol_version: ProtocolVersion::default(),
+        }
+    }
+
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Adds a header sent with every request, replacing any existing header with the same key.
+    pub fn header(mut self, key: &str, value: &str) -> Self {
+        self.headers.retain(|(k, _)| !k.eq_ignore_ascii_case(key));
+        self.headers.push((key.into(), value.into()));
+        self
+    }
+
+    pub fn key(mut self, key: &str) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.inner = self.inner.timeout(timeout);
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.inner = self.inner.connect_timeout(timeout);
+        self
+    }
+
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.inner = self.inner.proxy(proxy);
+        self
+    }
+
+    pub fn add_root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.inner = self.inner.add_root_certificate(certificate);
+        self
+    }
+
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.inner = self.inner.pool_max_idle_per_host(max);
+        self
+    }
+
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.inner = self.inner.pool_idle_timeout(timeout);
+        self
+    }
+
+    /// Uses an already configured `reqwest::Client`, ignoring any other transport settings
+    /// made on this builder.
+    pub fn reqwest_client(self, client: reqwest::Client) -> Self {
+        self.transport(ReqwestTransport::new(client))
+    }
+
+    /// Sends requests through a custom `Transport`, ignoring any other transport settings
+    /// made on this builder.
+    pub fn transport<T>(mut self, transport: T) -> Self
+    where
+        T: Transport + 'static,
+    {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Retries failed requests according to `retry_policy`. Requests are sent once by default.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Limits every request made through the client, including retries.
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(Arc::new(rate_limiter));
+        self
+    }
+
+    /// Caches successful responses on disk and revalidates them once stale.
+    pub fn cache(mut self, cache: HttpCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// The protocol request builders use unless they override it.
+    pub fn protocol_version(mut self, protocol_version: ProtocolVersion) -> Self {
+        self.protocol_version = protocol_version;
+        self
+    }
+
+    pub fn build(self) -> Result<SdmxClient> {
+        Url::parse(&self.base_url)?;
+
+        let transport = match self.transport {
+            Some(transport) => transport,
+            None => Arc::new(ReqwestTransport::new(self.inner.build()?)),
+        };
+
+        Ok(SdmxClient {
+            base_url: self.base_url,
+            headers: self.headers.into(),
+            key: self.key,
+            transport,
+            retry_policy: self.retry_policy,
+            rate_limiter: self.rate_limiter,
+            cache: self.cache,
+            protocol_version: self.protocol_version,
+        })
+    }
+}
+
+impl Default for SdmxClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
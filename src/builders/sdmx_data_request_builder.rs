@@ -1,15 +1,21 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+
 use crate::{
     builders::url_builder::UrlBuilder,
     config::Config,
+    error_code::ErrorCode,
     models::typed::{
-        dataflow_identifier::DataflowIdentifier, datakey::DataKey, detail::Detail,
+        data_format::DataFormat, dataflow_identifier::DataflowIdentifier,
+        dataflow_structure::DataflowStructure, datakey::DataKey, detail::Detail,
         dimension_at_observation::DimensionAtObservation, period::Period,
+        protocol_version::ProtocolVersion, sdmx_client::SdmxClient,
         sdmx_data_request::SdmxDataRequest, sdmx_request::SdmxRequest,
     },
+    result::Result,
 };
 
 pub struct SdmxDataRequestBuilder<'a> {
-    base_url: &'a str,
+    client: &'a SdmxClient,
     path: &'a str,
     dataflow_identifier: &'a DataflowIdentifier,
     data_key: Option<&'a DataKey>,
@@ -17,14 +23,21 @@ pub struct SdmxDataRequestBuilder<'a> {
     end_period: Option<&'a Period>,
     detail: Option<&'a Detail>,
     dimension_at_observation: Option<&'a DimensionAtObservation>,
+    updated_after: Option<Box<str>>,
+    first_n_observations: Option<u32>,
+    last_n_observations: Option<u32>,
+    include_history: Option<bool>,
+    component_filters: Vec<(&'a str, &'a str)>,
+    protocol_version: Option<ProtocolVersion>,
+    format: DataFormat,
+    structure: Option<&'a DataflowStructure>,
     key: Option<&'a str>,
-    headers: &'a [(&'a str, &'a str)],
 }
 
 impl<'a> SdmxDataRequestBuilder<'a> {
     pub fn new(dataflow_identifier: &'a DataflowIdentifier) -> Self {
         Self {
-            base_url: Config::BASE_URL,
+            client: SdmxClient::get_or_init(),
             path: Config::DATA_PATH,
             dataflow_identifier,
             data_key: None,
@@ -32,8 +45,15 @@ impl<'a> SdmxDataRequestBuilder<'a> {
             end_period: None,
             detail: None,
             dimension_at_observation: None,
+            updated_after: None,
+            first_n_observations: None,
+            last_n_observations: None,
+            include_history: None,
+            component_filters: Vec::new(),
+            protocol_version: None,
+            format: DataFormat::default(),
+            structure: None,
             key: None,
-            headers: &[Config::USER_AGENT_ANONYMOUS, Config::ACCEPT_DATA_JSON],
         }
     }
 
@@ -65,22 +85,118 @@ impl<'a> SdmxDataRequestBuilder<'a> {
         self
     }
 
+    pub fn updated_after(mut self, updated_after: &str) -> Self {
+        self.updated_after = Some(updated_after.into());
+        self
+    }
+
+    /// Only returns observations updated after `timestamp`, sent as RFC 3339 in UTC.
+    pub fn updated_after_timestamp(mut self, timestamp: DateTime<Utc>) -> Self {
+        self.updated_after = Some(timestamp.to_rfc3339_opts(SecondsFormat::Secs, true).into());
+        self
+    }
+
+    /// Returns at most the first `n` observations of each series.
+    pub fn first_n_observations(mut self, n: u32) -> Self {
+        self.first_n_observations = Some(n);
+        self
+    }
+
+    /// Returns at most the last `n` observations of each series.
+    pub fn last_n_observations(mut self, n: u32) -> Self {
+        self.last_n_observations = Some(n);
+        self
+    }
+
+    /// Whether to also return previous versions of the data, for use with `updated_after`.
+    pub fn include_history(mut self, include_history: bool) -> Self {
+        self.include_history = Some(include_history);
+        self
+    }
+
+    /// Filters a component by value with an SDMX 3.0 `c[COMPONENT]=value` parameter,
+    /// e.g. `("REGION", "1,8")` or `("OBS_VALUE", "ge:100")`. With SDMX 2.1, filters on a
+    /// series dimension of the `structure` are moved into the data key and any other
+    /// filter fails the build.
+    pub fn component_filter(mut self, component: &'a str, value: &'a str) -> Self {
+        self.component_filters.push((component, value));
+        self
+    }
+
+    /// Overrides the protocol version of the client for this request.
+    pub fn protocol_version(mut self, protocol_version: ProtocolVersion) -> Self {
+        self.protocol_version = Some(protocol_version);
+        self
+    }
+
+    /// The message format to request and parse. The `Accept` header follows from it.
+    pub fn format(mut self, format: DataFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// The dataflow structure used to tell series dimensions from attributes in SDMX-ML
+    /// structure specific data. It is fetched when the response needs it and none is set.
+    pub fn structure(mut self, structure: &'a DataflowStructure) -> Self {
+        self.structure = Some(structure);
+        self
+    }
+
+    pub fn client(mut self, client: &'a SdmxClient) -> Self {
+        self.client = client;
+        self
+    }
+
     pub fn key(mut self, key: &'a str) -> Self {
         self.key = Some(key);
         self
     }
 
-    pub fn build(&self) -> SdmxDataRequest {
-        let mut url_builder = UrlBuilder::new(self.base_url)
-            .add_path_segment(self.path)
-            .add_path_segment(self.dataflow_identifier.key());
+    /// The SDMX 2.1 key, with the component filters moved into their dimension positions.
+    fn sdmx_2_1_key(&self) -> Result<String> {
+        let no_filter = DataKey::no_filter();
+        let data_key = self.data_key.unwrap_or(&no_filter);
+
+        if self.component_filters.is_empty() {
+            return Ok(data_key.to_string());
+        }
+
+        let unsupported = |component: &str| ErrorCode::UnsupportedComponentFilter(component.into());
+
+        let Some(structure) = self.structure else {
+            let (component, _) = self.component_filters[0];
+            return Err(unsupported(component));
+        };
 
-        if let Some(data_key) = self.data_key {
-            url_builder = url_builder.add_path_segment(data_key.to_string());
-        } else {
-            url_builder = url_builder.add_path_segment(DataKey::default().to_string());
+        let mut positions = data_key
+            .positions()
+            .unwrap_or_else(|| vec![""; structure.dimensions().len()])
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>();
+
+        for (component, value) in &self.component_filters {
+            let position = structure
+                .position(component)
+                .filter(|position| *position < positions.len())
+                .ok_or_else(|| unsupported(component))?;
+
+            if !positions[position].is_empty() || value.contains(':') || value.is_empty() {
+                return Err(unsupported(component));
+            }
+
+            positions[position] = value.replace(',', "+");
         }
 
+        Ok(positions.join("."))
+    }
+
+    fn sdmx_2_1_url_builder(&self) -> Result<UrlBuilder> {
+        let mut url_builder = UrlBuilder::new(self.client.base_url())
+            .add_path_segment(self.path)
+            .add_path_segment(self.dataflow_identifier.key())
+            .add_path_segment(self.sdmx_2_1_key()?);
+
         if let Some(start_period) = &self.start_period {
             url_builder =
                 url_builder.add_query_param(Config::QUERY_START_PERIOD, start_period.to_string());
@@ -92,17 +208,106 @@ impl<'a> SdmxDataRequestBuilder<'a> {
         if let Some(detail) = &self.detail {
             url_builder = url_builder.add_query_param(Config::QUERY_DETAIL, detail.to_string());
         }
+
+        Ok(url_builder)
+    }
+
+    fn sdmx_3_0_url_builder(&self) -> UrlBuilder {
+        let id = self.dataflow_identifier;
+
+        let mut url_builder = UrlBuilder::new(self.client.base_url())
+            .add_path_segment(self.path)
+            .add_path_segment(Config::DATAFLOW_PATH)
+            .add_path_segment(id.agency_id().unwrap_or(Config::DEFAULT_AGENCY_ID))
+            .add_path_segment(id.structure_id())
+            .add_path_segment(
+                id.version()
+                    .map(AsRef::as_ref)
+                    .unwrap_or(Config::LATEST_VERSION),
+            );
+
+        match self.data_key {
+            Some(data_key) => url_builder = url_builder.add_path_segment(data_key.sdmx_3_0_key()),
+            None => url_builder = url_builder.add_path_segment(Config::WILDCARD),
+        }
+
+        let time_period = [
+            self.start_period.map(|p| format!("ge:{}", p)),
+            self.end_period.map(|p| format!("le:{}", p)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        if !time_period.is_empty() {
+            url_builder = url_builder.add_query_param(
+                format!("c[{}]", Config::QUERY_TIME_PERIOD_COMPONENT),
+                time_period.join("+"),
+            );
+        }
+        for (component, value) in &self.component_filters {
+            url_builder = url_builder.add_query_param(format!("c[{}]", component), *value);
+        }
+        if let Some((attributes, measures)) = self
+            .detail
+            .and_then(Detail::sdmx_3_0_attributes_and_measures)
+        {
+            url_builder = url_builder
+                .add_query_param(Config::QUERY_ATTRIBUTES, attributes)
+                .add_query_param(Config::QUERY_MEASURES, measures);
+        }
+
+        url_builder
+    }
+
+    /// Fails when a component filter cannot be expressed with the protocol version.
+    pub fn build(&self) -> Result<SdmxDataRequest<'a>> {
+        let protocol_version = self
+            .protocol_version
+            .unwrap_or(self.client.protocol_version());
+
+        let mut url_builder = match protocol_version {
+            ProtocolVersion::Sdmx21 => self.sdmx_2_1_url_builder()?,
+            ProtocolVersion::Sdmx30 => self.sdmx_3_0_url_builder(),
+        };
+        let headers = self.format.accept_headers(protocol_version);
+
         if let Some(dimension_at_observation) = &self.dimension_at_observation {
             url_builder = url_builder.add_query_param(
                 Config::QUERY_DIMENSION_AT_OBSERVATION,
                 dimension_at_observation.to_string(),
             );
         }
+        if let Some(updated_after) = &self.updated_after {
+            url_builder =
+                url_builder.add_query_param(Config::QUERY_UPDATED_AFTER, updated_after.as_ref());
+        }
+        if let Some(first_n_observations) = self.first_n_observations {
+            url_builder = url_builder.add_query_param(
+                Config::QUERY_FIRST_N_OBSERVATIONS,
+                first_n_observations.to_string(),
+            );
+        }
+        if let Some(last_n_observations) = self.last_n_observations {
+            url_builder = url_builder.add_query_param(
+                Config::QUERY_LAST_N_OBSERVATIONS,
+                last_n_observations.to_string(),
+            );
+        }
+        if let Some(include_history) = self.include_history {
+            url_builder = url_builder
+                .add_query_param(Config::QUERY_INCLUDE_HISTORY, include_history.to_string());
+        }
 
-        let url = url_builder.build().expect("Failed to build url");
+        let url = url_builder.build()?;
 
-        let request = SdmxRequest::new(url, self.key, self.headers);
+        let request = SdmxRequest::new(self.client, url, self.key, headers);
 
-        SdmxDataRequest::from(request)
+        Ok(SdmxDataRequest::new(
+            request,
+            self.format,
+            self.dataflow_identifier,
+            self.structure,
+        ))
     }
 }
@@ -2,35 +2,38 @@ use crate::{
     builders::url_builder::UrlBuilder,
     config::Config,
     models::typed::{
-        meta_detail::MetaDetail, reference::Reference, sdmx_meta_request::SdmxMetaRequest,
-        sdmx_request::SdmxRequest, structure_type::StructureType, version::Version,
+        meta_detail::MetaDetail, protocol_version::ProtocolVersion, reference::Reference,
+        sdmx_client::SdmxClient, sdmx_meta_request::SdmxMetaRequest, sdmx_request::SdmxRequest,
+        structure_format::StructureFormat, structure_type::StructureType, version::Version,
     },
 };
 
 pub struct SdmxMetaRequestBuilder<'a> {
-    base_url: &'a str,
+    client: &'a SdmxClient,
     structure_type: &'a StructureType,
     agency_id: Option<&'a str>,
     detail: Option<&'a MetaDetail>,
     structure_id: Option<&'a str>,
     structure_version: Option<&'a Version>,
     references: Option<&'a Reference>,
+    protocol_version: Option<ProtocolVersion>,
+    format: StructureFormat,
     key: Option<&'a str>,
-    headers: &'a [(&'a str, &'a str)],
 }
 
 impl<'a> SdmxMetaRequestBuilder<'a> {
     pub fn new(structure_type: &'a StructureType) -> Self {
         Self {
-            base_url: Config::BASE_URL,
+            client: SdmxClient::get_or_init(),
             structure_type,
             agency_id: None,
             detail: None,
             structure_id: None,
             structure_version: None,
             references: None,
+            protocol_version: None,
+            format: StructureFormat::default(),
             key: None,
-            headers: &[Config::USER_AGENT_ANONYMOUS, Config::ACCEPT_STRUCTURE_JSON],
         }
     }
 
@@ -59,15 +62,43 @@ impl<'a> SdmxMetaRequestBuilder<'a> {
         self
     }
 
+    /// Overrides the protocol version of the client for this request.
+    pub fn protocol_version(mut self, protocol_version: ProtocolVersion) -> Self {
+        self.protocol_version = Some(protocol_version);
+        self
+    }
+
+    /// The message format to request and parse. The `Accept` header follows from it.
+    pub fn format(mut self, format: StructureFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn client(mut self, client: &'a SdmxClient) -> Self {
+        self.client = client;
+        self
+    }
+
     pub fn key(mut self, key: &'a str) -> Self {
         self.key = Some(key);
         self
     }
 
-    pub fn build(&self) -> SdmxMetaRequest {
-        let mut url_builder = UrlBuilder::new(self.base_url)
-            .add_path_segment(self.structure_type.to_string())
-            .add_path_segment(self.agency_id.unwrap_or("ABS"));
+    pub fn build(&self) -> SdmxMetaRequest<'a> {
+        let protocol_version = self
+            .protocol_version
+            .unwrap_or(self.client.protocol_version());
+
+        let mut url_builder = match protocol_version {
+            ProtocolVersion::Sdmx21 => UrlBuilder::new(self.client.base_url())
+                .add_path_segment(self.structure_type.to_string()),
+            ProtocolVersion::Sdmx30 => UrlBuilder::new(self.client.base_url())
+                .add_path_segment(Config::STRUCTURE_PATH)
+                .add_path_segment(self.structure_type.sdmx_3_0_name()),
+        };
+
+        url_builder =
+            url_builder.add_path_segment(self.agency_id.unwrap_or(Config::DEFAULT_AGENCY_ID));
 
         if let Some(structure_id) = self.structure_id {
             url_builder = url_builder.add_path_segment(structure_id.to_string());
@@ -78,8 +109,11 @@ impl<'a> SdmxMetaRequestBuilder<'a> {
         }
 
         if let Some(references) = self.references {
-            url_builder =
-                url_builder.add_query_param(Config::QUERY_REFERENCES, references.to_string());
+            let references = match protocol_version {
+                ProtocolVersion::Sdmx21 => references.to_string(),
+                ProtocolVersion::Sdmx30 => references.sdmx_3_0_name(),
+            };
+            url_builder = url_builder.add_query_param(Config::QUERY_REFERENCES, references);
         }
 
         if let Some(detail) = self.detail {
@@ -88,8 +122,8 @@ impl<'a> SdmxMetaRequestBuilder<'a> {
 
         let url = url_builder.build().expect("Failed to build url");
 
-        let request = SdmxRequest::new(url, self.key, self.headers);
+        let request = SdmxRequest::new(self.client, url, self.key, self.format.accept_headers());
 
-        SdmxMetaRequest::from(request)
+        SdmxMetaRequest::new(request, self.format)
     }
 }
@@ -3,6 +3,11 @@ pub struct Config;
 impl Config {
     pub const BASE_URL: &str = "https://api.data.abs.gov.au";
     pub const DATA_PATH: &str = "data";
+    pub const DATAFLOW_PATH: &str = "dataflow";
+    pub const STRUCTURE_PATH: &str = "structure";
+    pub const DEFAULT_AGENCY_ID: &str = "ABS";
+    pub const LATEST_VERSION: &str = "~";
+    pub const WILDCARD: &str = "*";
     pub const DATA_KEY_MAX_LENGTH: usize = 260;
     pub const DATA_KEY_REQUIRED_DOT_COUNT: usize = 4;
 
@@ -11,13 +16,36 @@ impl Config {
     pub const QUERY_DETAIL: &str = "detail";
     pub const QUERY_DIMENSION_AT_OBSERVATION: &str = "dimensionAtObservation";
     pub const QUERY_REFERENCES: &str = "references";
+    pub const QUERY_UPDATED_AFTER: &str = "updatedAfter";
+    pub const QUERY_FIRST_N_OBSERVATIONS: &str = "firstNObservations";
+    pub const QUERY_LAST_N_OBSERVATIONS: &str = "lastNObservations";
+    pub const QUERY_INCLUDE_HISTORY: &str = "includeHistory";
+    pub const QUERY_ATTRIBUTES: &str = "attributes";
+    pub const QUERY_MEASURES: &str = "measures";
+    pub const QUERY_TIME_PERIOD_COMPONENT: &str = "TIME_PERIOD";
+
+    pub const TIME_PERIOD_DIMENSION: &str = "TIME_PERIOD";
+    pub const PRIMARY_MEASURE: &str = "OBS_VALUE";
 
     pub const MIME_TYPE_SDMX_STRUCTURE_JSON: &str = "application/vnd.sdmx.structure+json";
+    pub const MIME_TYPE_SDMX_STRUCTURE_XML: &str = "application/vnd.sdmx.structure+xml;version=2.1";
     pub const MIME_TYPE_SDMX_DATA_JSON: &str = "application/vnd.sdmx.data+json";
+    pub const MIME_TYPE_SDMX_DATA_JSON_2_0: &str = "application/vnd.sdmx.data+json;version=2.0.0";
+    pub const MIME_TYPE_SDMX_DATA_CSV: &str = "application/vnd.sdmx.data+csv;version=1.0.0";
+    pub const MIME_TYPE_SDMX_DATA_CSV_2_0: &str = "application/vnd.sdmx.data+csv;version=2.0.0";
+    pub const MIME_TYPE_SDMX_STRUCTURE_SPECIFIC_DATA_XML: &str =
+        "application/vnd.sdmx.structurespecificdata+xml;version=2.1";
+    pub const MIME_TYPE_SDMX_GENERIC_DATA_XML: &str =
+        "application/vnd.sdmx.genericdata+xml;version=2.1";
 
     pub const HEADER_ACCEPT_KEY: &str = "Accept";
     pub const HEADER_USER_AGENT_KEY: &str = "User-Agent";
     pub const HEADER_API_KEY: &str = "x-api-key";
+    pub const HEADER_RETRY_AFTER_KEY: &str = "Retry-After";
+    pub const HEADER_ETAG_KEY: &str = "ETag";
+    pub const HEADER_LAST_MODIFIED_KEY: &str = "Last-Modified";
+    pub const HEADER_IF_NONE_MATCH_KEY: &str = "If-None-Match";
+    pub const HEADER_IF_MODIFIED_SINCE_KEY: &str = "If-Modified-Since";
     pub const HEADER_USER_AGENT_VALUE: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36";
 
     pub const USER_AGENT_ANONYMOUS: (&str, &str) =
@@ -26,6 +54,28 @@ impl Config {
     pub const ACCEPT_STRUCTURE_JSON: (&str, &str) =
         (Self::HEADER_ACCEPT_KEY, Self::MIME_TYPE_SDMX_STRUCTURE_JSON);
 
+    pub const ACCEPT_STRUCTURE_XML: (&str, &str) =
+        (Self::HEADER_ACCEPT_KEY, Self::MIME_TYPE_SDMX_STRUCTURE_XML);
+
     pub const ACCEPT_DATA_JSON: (&str, &str) =
         (Self::HEADER_ACCEPT_KEY, Self::MIME_TYPE_SDMX_DATA_JSON);
+
+    pub const ACCEPT_DATA_JSON_2_0: (&str, &str) =
+        (Self::HEADER_ACCEPT_KEY, Self::MIME_TYPE_SDMX_DATA_JSON_2_0);
+
+    pub const ACCEPT_DATA_CSV: (&str, &str) =
+        (Self::HEADER_ACCEPT_KEY, Self::MIME_TYPE_SDMX_DATA_CSV);
+
+    pub const ACCEPT_DATA_CSV_2_0: (&str, &str) =
+        (Self::HEADER_ACCEPT_KEY, Self::MIME_TYPE_SDMX_DATA_CSV_2_0);
+
+    pub const ACCEPT_DATA_STRUCTURE_SPECIFIC_XML: (&str, &str) = (
+        Self::HEADER_ACCEPT_KEY,
+        Self::MIME_TYPE_SDMX_STRUCTURE_SPECIFIC_DATA_XML,
+    );
+
+    pub const ACCEPT_DATA_GENERIC_XML: (&str, &str) = (
+        Self::HEADER_ACCEPT_KEY,
+        Self::MIME_TYPE_SDMX_GENERIC_DATA_XML,
+    );
 }
@@ -50,6 +50,70 @@ pub enum ErrorCode {
     #[error("Version string length not exactly three: {0}")]
     VersionStringNotCorrectLength(usize),
 
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Requests per second must be finite and greater than zero: {0}")]
+    InvalidRequestsPerSecond(f64),
+
+    #[error("No recorded interaction in cassette for: {0}")]
+    CassetteInteractionNotFound(Box<str>),
+
+    #[error("Dataflow structure not in registry: {0}")]
+    StructureNotInRegistry(Box<str>),
+
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+
+    #[cfg(feature = "xml")]
+    #[error("XML error: {0}")]
+    Xml(#[from] quick_xml::Error),
+
+    #[cfg(feature = "arrow")]
+    #[error("Arrow error: {0}")]
+    Arrow(#[from] arrow_schema::ArrowError),
+
+    #[cfg(feature = "parquet")]
+    #[error("Parquet error: {0}")]
+    Parquet(#[from] parquet::errors::ParquetError),
+
+    #[cfg(feature = "polars")]
+    #[error("Polars error: {0}")]
+    Polars(#[from] polars::error::PolarsError),
+
+    #[error("Unsupported data message: {0}")]
+    UnsupportedDataMessage(Box<str>),
+
+    #[error("Unsupported structure message: {0}")]
+    UnsupportedStructureMessage(Box<str>),
+
+    #[error("Period string could not be parsed: {0}")]
+    PeriodParse(Box<str>),
+
+    #[error("Period has no calendar dates: {0}")]
+    InvalidPeriod(Box<str>),
+
+    #[error("Periods {0} and {1} cannot form a range")]
+    PeriodRangeMismatch(Box<str>, Box<str>),
+
+    #[error("Data stream was dropped before it was read in full")]
+    StreamClosed,
+
+    #[error("Component filter cannot be sent as an SDMX 2.1 key: {0}")]
+    UnsupportedComponentFilter(Box<str>),
+
+    #[error("Code {1} is not a value of {0} in the structure")]
+    UnknownCode(Box<str>, Box<str>),
+
+    #[error("Attribute is not in the structure: {0}")]
+    UnknownAttribute(Box<str>),
+
+    #[error("Series in one frame have different frequencies: {0} and {1}")]
+    MixedFrequencies(Box<str>, Box<str>),
+
     #[error("Custom error: {0}")]
     Custom(Box<str>),
 }
@@ -0,0 +1,178 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use arrow_array::{
+    types::{Date32Type, Int32Type},
+    ArrayRef, Date32Array, DictionaryArray, Float64Array, Int32Array, RecordBatch, StringArray,
+};
+use arrow_schema::{DataType, Field, Schema};
+
+use crate::{
+    config::Config,
+    error_code::ErrorCode,
+    exporters,
+    models::{
+        derived::{data_sets::DataSets, sdmx_response::SdmxResponse, value::Value},
+        typed::period::Period,
+    },
+    result::Result,
+};
+
+/// Field metadata key holding the component name.
+pub const METADATA_NAME: &str = "name";
+/// Field metadata key holding a JSON object of codes to labels.
+pub const METADATA_LABELS: &str = "labels";
+
+/// The labels of component values as a JSON object of codes to labels.
+pub(crate) fn labels(values: &[Value]) -> Result<String> {
+    let labels = values
+        .iter()
+        .map(|value| (value.id.as_ref(), value.name.as_ref()))
+        .collect::<BTreeMap<_, _>>();
+
+    Ok(serde_json::to_string(&labels)?)
+}
+
+/// A dictionary encoded column whose dictionary is the component's codes, in structure
+/// order, so that keys are the indexes of the SDMX-JSON message.
+struct CodeColumn<'a> {
+    id: &'a str,
+    name: &'a str,
+    values: &'a [Value],
+    positions: HashMap<&'a str, i32>,
+    keys: Vec<Option<i32>>,
+}
+
+impl<'a> CodeColumn<'a> {
+    fn new(id: &'a str, name: &'a str, values: &'a [Value]) -> Self {
+        Self {
+            id,
+            name,
+            values,
+            positions: values
+                .iter()
+                .enumerate()
+                .map(|(index, value)| (value.id.as_ref(), index as i32))
+                .collect(),
+            keys: Vec::new(),
+        }
+    }
+
+    /// Fails on a code that is not one of the column's values, rather than writing it
+    /// as missing.
+    fn push(&mut self, code: Option<&str>) -> Result<()> {
+        let key = match code {
+            Some(code) => Some(
+                *self
+                    .positions
+                    .get(code)
+                    .ok_or_else(|| ErrorCode::UnknownCode(self.id.into(), code.into()))?,
+            ),
+            None => None,
+        };
+
+        self.keys.push(key);
+
+        Ok(())
+    }
+
+    fn field(&self) -> Result<Field> {
+        let metadata = HashMap::from([
+            (METADATA_NAME.to_string(), self.name.to_string()),
+            (METADATA_LABELS.to_string(), labels(self.values)?),
+        ]);
+
+        let data_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+
+        Ok(Field::new(self.id, data_type, true).with_metadata(metadata))
+    }
+
+    fn array(self) -> Result<ArrayRef> {
+        let codes = StringArray::from_iter_values(self.values.iter().map(|value| &value.id));
+        let array =
+            DictionaryArray::<Int32Type>::try_new(Int32Array::from(self.keys), Arc::new(codes))?;
+
+        Ok(Arc::new(array))
+    }
+}
+
+/// Converts the observations of `data_sets` into one row each.
+///
+/// Dimensions and attributes become dictionary encoded code columns named by their id,
+/// with their name and labels in the field metadata. `TIME_PERIOD` becomes the first day
+/// of each period as a `Date32`, and `OBS_VALUE` a `Float64`.
+pub fn record_batch(data_sets: &DataSets) -> Result<RecordBatch> {
+    let structure = data_sets
+        .structure
+        .as_ref()
+        .ok_or(ErrorCode::MissingExpectedOptionalField("structure".into()))?;
+
+    let mut dimensions = exporters::dimensions(structure)
+        .map(|(id, name, values)| CodeColumn::new(id, name, values))
+        .collect::<Vec<_>>();
+    let mut attributes = exporters::attributes(structure)
+        .map(|(id, name, values)| CodeColumn::new(id, name, values))
+        .collect::<Vec<_>>();
+
+    let mut time_periods = Vec::new();
+    let mut obs_values = Vec::new();
+
+    for observation in data_sets.observations()? {
+        for column in &mut dimensions {
+            column.push(observation.dimension(column.id).map(|d| d.code))?;
+        }
+        for column in &mut attributes {
+            column.push(observation.attribute(column.id).map(|a| a.code))?;
+        }
+
+        let time_period = match observation.time_period {
+            Some(time_period) => Some(Period::parse(time_period)?.first_date()?),
+            None => None,
+        };
+
+        time_periods.push(time_period.map(Date32Type::from_naive_date));
+        obs_values.push(observation.value);
+    }
+
+    let mut fields = Vec::new();
+    let mut columns = Vec::<ArrayRef>::new();
+
+    for column in dimensions {
+        fields.push(column.field()?);
+        columns.push(column.array()?);
+    }
+
+    fields.push(Field::new(
+        Config::TIME_PERIOD_DIMENSION,
+        DataType::Date32,
+        true,
+    ));
+    columns.push(Arc::new(Date32Array::from(time_periods)));
+
+    fields.push(Field::new(Config::PRIMARY_MEASURE, DataType::Float64, true));
+    columns.push(Arc::new(Float64Array::from(obs_values)));
+
+    for column in attributes {
+        fields.push(column.field()?);
+        columns.push(column.array()?);
+    }
+
+    let metadata = HashMap::from([(METADATA_NAME.to_string(), structure.name.to_string())]);
+    let schema = Schema::new_with_metadata(fields, metadata);
+
+    Ok(RecordBatch::try_new(Arc::new(schema), columns)?)
+}
+
+impl DataSets {
+    pub fn to_record_batch(&self) -> Result<RecordBatch> {
+        record_batch(self)
+    }
+}
+
+impl SdmxResponse<DataSets> {
+    pub fn to_record_batch(&self) -> Result<RecordBatch> {
+        record_batch(&self.data)
+    }
+}
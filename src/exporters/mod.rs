@@ -0,0 +1,48 @@
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod observation_writer;
+#[cfg(feature = "parquet")]
+pub mod parquet;
+#[cfg(feature = "polars")]
+pub mod polars;
+
+use crate::{
+    config::Config,
+    models::derived::{structure::Structure, value::Value},
+};
+
+/// A dimension or attribute column: its id, name and values.
+pub(crate) type Component<'a> = (&'a str, &'a str, &'a [Value]);
+
+/// The series then observation dimensions of `structure`, without `TIME_PERIOD`.
+pub(crate) fn dimensions(structure: &Structure) -> impl Iterator<Item = Component<'_>> {
+    let series = structure
+        .dimensions
+        .series
+        .iter()
+        .map(|d| (d.id.as_ref(), d.name.as_ref(), d.values.as_ref()));
+    let observation = structure
+        .dimensions
+        .observation
+        .iter()
+        .filter(|d| d.id.as_ref() != Config::TIME_PERIOD_DIMENSION)
+        .map(|d| (d.id.as_ref(), d.name.as_ref(), d.values.as_ref()));
+
+    series.chain(observation)
+}
+
+/// The series then observation attributes of `structure`.
+pub(crate) fn attributes(structure: &Structure) -> impl Iterator<Item = Component<'_>> {
+    let series = structure
+        .attributes
+        .series
+        .iter()
+        .map(|a| (a.id.as_ref(), a.name.as_ref(), a.values.as_ref()));
+    let observation = structure
+        .attributes
+        .observation
+        .iter()
+        .map(|a| (a.id.as_ref(), a.name.as_ref(), a.values.as_ref()));
+
+    series.chain(observation)
+}
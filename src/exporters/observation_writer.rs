@@ -0,0 +1,278 @@
+use std::io::Write;
+
+use serde::{ser::SerializeMap, Serialize, Serializer};
+use serde_json::value::RawValue;
+
+use crate::{
+    builders::observation_writer_builder::ObservationWriterBuilder,
+    config::Config,
+    error_code::ErrorCode,
+    exporters,
+    models::derived::{
+        data_point::DataPoint,
+        data_sets::DataSets,
+        flat_observation::{FlatObservation, ResolvedComponent},
+        sdmx_response::SdmxResponse,
+        structure::Structure,
+    },
+    result::Result,
+};
+
+/// Where the cells of a column come from.
+enum Source<'a> {
+    Dimension(&'a str),
+    DimensionLabel(&'a str),
+    TimePeriod,
+    ObsValue,
+    Attribute(&'a str),
+    AttributeLabel(&'a str),
+}
+
+struct Column<'a> {
+    name: String,
+    source: Source<'a>,
+}
+
+enum Cell<'a> {
+    Text(Option<&'a str>),
+    Value(Option<&'a DataPoint>),
+}
+
+impl<'a> Cell<'a> {
+    /// The cell as written in the message, with an absent value or JSON `null` as `None`.
+    fn lexical(&self) -> Option<&'a str> {
+        match self {
+            Self::Text(text) => *text,
+            Self::Value(data_point) => data_point
+                .map(DataPoint::lexical)
+                .filter(|lexical| *lexical != "null"),
+        }
+    }
+
+    /// The cell as CSV text, with absent values left empty.
+    fn text(&self) -> &'a str {
+        self.lexical().unwrap_or_default()
+    }
+}
+
+/// `text` as a JSON number, exactly as written, when it is one.
+fn json_number(text: &str) -> Option<Box<RawValue>> {
+    text.starts_with(|c: char| c == '-' || c.is_ascii_digit())
+        .then(|| RawValue::from_string(text.to_string()).ok())
+        .flatten()
+}
+
+impl Serialize for Cell<'_> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match (self, self.lexical()) {
+            (Self::Value(_), Some(lexical)) => match json_number(lexical) {
+                Some(number) => number.serialize(serializer),
+                None => lexical.serialize(serializer),
+            },
+            (_, lexical) => lexical.serialize(serializer),
+        }
+    }
+}
+
+/// One observation as a JSON object, with its fields in column order.
+struct Row<'a, 'b> {
+    columns: &'b [Column<'a>],
+    cells: &'b [Cell<'a>],
+}
+
+impl Serialize for Row<'_, '_> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.columns.len()))?;
+        for (column, cell) in self.columns.iter().zip(self.cells) {
+            map.serialize_entry(&column.name, cell)?;
+        }
+        map.end()
+    }
+}
+
+/// Writes decoded observations as tidy CSV or JSON Lines, one observation per row, with
+/// a column per dimension, `TIME_PERIOD`, `OBS_VALUE` and a column per attribute.
+///
+/// Rows are written from a response already held in memory, decoding each observation as
+/// its row is written rather than building the whole table first.
+pub struct ObservationWriter {
+    labels: bool,
+    attributes: Option<Box<[Box<str>]>>,
+    delimiter: u8,
+}
+
+impl ObservationWriter {
+    pub(crate) fn new(labels: bool, attributes: Option<Box<[Box<str>]>>, delimiter: u8) -> Self {
+        Self {
+            labels,
+            attributes,
+            delimiter,
+        }
+    }
+
+    pub fn builder() -> ObservationWriterBuilder {
+        ObservationWriterBuilder::new()
+    }
+
+    /// The columns to write, failing on a chosen attribute the structure does not have.
+    fn columns<'a>(&'a self, structure: &'a Structure) -> Result<Vec<Column<'a>>> {
+        let mut columns = Vec::new();
+
+        for (id, ..) in exporters::dimensions(structure) {
+            columns.push(Column {
+                name: id.to_string(),
+                source: Source::Dimension(id),
+            });
+            if self.labels {
+                columns.push(Column {
+                    name: format!("{}_LABEL", id),
+                    source: Source::DimensionLabel(id),
+                });
+            }
+        }
+
+        columns.push(Column {
+            name: Config::TIME_PERIOD_DIMENSION.to_string(),
+            source: Source::TimePeriod,
+        });
+        columns.push(Column {
+            name: Config::PRIMARY_MEASURE.to_string(),
+            source: Source::ObsValue,
+        });
+
+        let known = exporters::attributes(structure)
+            .map(|(id, ..)| id)
+            .collect::<Vec<_>>();
+
+        let attributes = match &self.attributes {
+            Some(attributes) => attributes
+                .iter()
+                .map(|id| {
+                    known
+                        .contains(&id.as_ref())
+                        .then_some(id.as_ref())
+                        .ok_or_else(|| ErrorCode::UnknownAttribute(id.clone()))
+                })
+                .collect::<Result<Vec<_>>>()?,
+            None => known,
+        };
+
+        for id in attributes {
+            columns.push(Column {
+                name: id.to_string(),
+                source: Source::Attribute(id),
+            });
+            if self.labels {
+                columns.push(Column {
+                    name: format!("{}_LABEL", id),
+                    source: Source::AttributeLabel(id),
+                });
+            }
+        }
+
+        Ok(columns)
+    }
+
+    fn cells<'a>(columns: &[Column], observation: &FlatObservation<'a>) -> Vec<Cell<'a>> {
+        let code = |component: Option<&ResolvedComponent<'a>>| component.map(|c| c.code);
+        let label = |component: Option<&ResolvedComponent<'a>>| component.map(|c| c.label);
+
+        columns
+            .iter()
+            .map(|column| match column.source {
+                Source::Dimension(id) => Cell::Text(code(observation.dimension(id))),
+                Source::DimensionLabel(id) => Cell::Text(label(observation.dimension(id))),
+                Source::TimePeriod => Cell::Text(observation.time_period),
+                Source::ObsValue => Cell::Value(observation.data_point),
+                Source::Attribute(id) => Cell::Text(code(observation.attribute(id))),
+                Source::AttributeLabel(id) => Cell::Text(label(observation.attribute(id))),
+            })
+            .collect()
+    }
+
+    fn structure(data_sets: &DataSets) -> Result<&Structure> {
+        data_sets
+            .structure
+            .as_ref()
+            .ok_or(ErrorCode::MissingExpectedOptionalField("structure".into()))
+    }
+
+    /// Writes a header row and then a row per observation, with `OBS_VALUE` as written in
+    /// the message, including missing value markers such as `NaN`.
+    pub fn write_csv<W>(&self, data_sets: &DataSets, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        let columns = self.columns(Self::structure(data_sets)?)?;
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(self.delimiter)
+            .from_writer(writer);
+
+        writer.write_record(columns.iter().map(|column| &column.name))?;
+
+        for observation in data_sets.observations()? {
+            let cells = Self::cells(&columns, &observation);
+            writer.write_record(cells.iter().map(Cell::text))?;
+        }
+
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Writes a JSON object per observation and line, with absent values as `null`.
+    /// `OBS_VALUE` is written as in CSV, with numbers as JSON numbers exactly as written and
+    /// missing value markers as strings.
+    pub fn write_ndjson<W>(&self, data_sets: &DataSets, mut writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        let columns = self.columns(Self::structure(data_sets)?)?;
+
+        for observation in data_sets.observations()? {
+            let cells = Self::cells(&columns, &observation);
+            serde_json::to_writer(
+                &mut writer,
+                &Row {
+                    columns: &columns,
+                    cells: &cells,
+                },
+            )?;
+            writer.write_all(b"\n")?;
+        }
+
+        writer.flush()?;
+
+        Ok(())
+    }
+}
+
+impl Default for ObservationWriter {
+    fn default() -> Self {
+        ObservationWriterBuilder::new().build()
+    }
+}
+
+impl SdmxResponse<DataSets> {
+    /// Writes the observations as tidy CSV with codes and every attribute.
+    pub fn write_csv<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        ObservationWriter::default().write_csv(&self.data, writer)
+    }
+
+    /// Writes the observations as JSON Lines with codes and every attribute.
+    pub fn write_ndjson<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        ObservationWriter::default().write_ndjson(&self.data, writer)
+    }
+}
@@ -0,0 +1,204 @@
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::Path,
+    process,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use parquet::{arrow::ArrowWriter, file::properties::WriterProperties, format::KeyValue};
+
+use crate::{
+    builders::sdmx_meta_request_builder::SdmxMetaRequestBuilder,
+    config::Config,
+    error_code::ErrorCode,
+    exporters::arrow,
+    models::{
+        derived::{data_sets::DataSets, sdmx_response::SdmxResponse},
+        typed::{
+            dataflow_identifier::DataflowIdentifier, sdmx_data_request::SdmxDataRequest,
+            structure_type::StructureType, version::Version,
+        },
+    },
+    result::Result,
+};
+
+/// Numbers the temporary files of concurrent saves, so no two share a name.
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// File metadata key holding the dataflow id.
+pub const METADATA_DATAFLOW_ID: &str = "dataflow_id";
+/// File metadata key holding the dataflow agency id.
+pub const METADATA_AGENCY_ID: &str = "agency_id";
+/// File metadata key holding the dataflow version, resolved from the response when the
+/// latest was requested.
+pub const METADATA_VERSION: &str = "version";
+/// File metadata key holding when the message was prepared.
+pub const METADATA_PREPARED: &str = "prepared";
+/// Prefix of the file metadata keys holding each dimension's labels, such as
+/// `labels.REGION`, as a JSON object of codes to labels.
+pub const METADATA_LABELS_PREFIX: &str = "labels.";
+
+fn key_value_metadata(
+    response: &SdmxResponse<DataSets>,
+    dataflow_identifier: &DataflowIdentifier,
+) -> Result<Vec<KeyValue>> {
+    let structure = response
+        .data
+        .structure
+        .as_ref()
+        .ok_or(ErrorCode::MissingExpectedOptionalField("structure".into()))?;
+
+    let mut metadata = vec![
+        KeyValue::new(
+            METADATA_DATAFLOW_ID.to_string(),
+            dataflow_identifier.structure_id().to_string(),
+        ),
+        KeyValue::new(
+            METADATA_AGENCY_ID.to_string(),
+            dataflow_identifier
+                .agency_id()
+                .unwrap_or(Config::DEFAULT_AGENCY_ID)
+                .to_string(),
+        ),
+    ];
+
+    let version = dataflow_identifier
+        .version()
+        .cloned()
+        .or_else(|| response.data.dataflow_version())
+        .ok_or(ErrorCode::MissingExpectedOptionalField(
+            "dataflow version".into(),
+        ))?;
+
+    metadata.push(KeyValue::new(
+        METADATA_VERSION.to_string(),
+        version.to_string(),
+    ));
+
+    if !response.meta.prepared.is_empty() {
+        metadata.push(KeyValue::new(
+            METADATA_PREPARED.to_string(),
+            response.meta.prepared.to_string(),
+        ));
+    }
+
+    let dimensions = structure
+        .dimensions
+        .series
+        .iter()
+        .map(|d| (&d.id, &d.values))
+        .chain(
+            structure
+                .dimensions
+                .observation
+                .iter()
+                .map(|d| (&d.id, &d.values)),
+        );
+
+    for (id, values) in dimensions {
+        metadata.push(KeyValue::new(
+            format!("{}{}", METADATA_LABELS_PREFIX, id),
+            arrow::labels(values)?,
+        ));
+    }
+
+    Ok(metadata)
+}
+
+/// Writes the observations of `response` as one Parquet row each, in the columns of
+/// [`arrow::record_batch`], with the dataflow, when the message was prepared and the
+/// dimension labels as file metadata. Fails when `dataflow_identifier` has no version
+/// and the response does not link to its dataflow.
+pub fn write_parquet<W>(
+    writer: W,
+    response: &SdmxResponse<DataSets>,
+    dataflow_identifier: &DataflowIdentifier,
+) -> Result<()>
+where
+    W: Write + Send,
+{
+    let batch = response.to_record_batch()?;
+    let properties = WriterProperties::builder()
+        .set_key_value_metadata(Some(key_value_metadata(response, dataflow_identifier)?))
+        .build();
+
+    let mut writer = ArrowWriter::try_new(writer, batch.schema(), Some(properties))?;
+    writer.write(&batch)?;
+    writer.close()?;
+
+    Ok(())
+}
+
+impl SdmxDataRequest<'_> {
+    /// The requested dataflow, with its version resolved from the response or, failing
+    /// that, from the dataflow's metadata.
+    async fn versioned_dataflow_identifier(
+        &self,
+        response: &SdmxResponse<DataSets>,
+    ) -> Result<DataflowIdentifier> {
+        let id = self.dataflow_identifier();
+
+        if id.version().is_some() {
+            return Ok(id.clone());
+        }
+
+        let version = match response.data.dataflow_version() {
+            Some(version) => version,
+            None => self.fetch_dataflow_version().await?,
+        };
+
+        Ok(DataflowIdentifier::new(
+            id.agency_id().map(Into::into),
+            id.structure_id().into(),
+            Some(version),
+        ))
+    }
+
+    async fn fetch_dataflow_version(&self) -> Result<Version> {
+        let id = self.dataflow_identifier();
+        let mut builder = SdmxMetaRequestBuilder::new(&StructureType::DataFlow)
+            .client(self.client())
+            .structure_id(id.structure_id());
+
+        if let Some(agency_id) = id.agency_id() {
+            builder = builder.agency_id(agency_id)
+        }
+
+        let dataflows = builder.build().send().await?.data.dataflows;
+
+        dataflows
+            .as_deref()
+            .and_then(<[_]>::first)
+            .map(|dataflow| dataflow.version.clone())
+            .ok_or(ErrorCode::MissingExpectedOptionalField(
+                "dataflow version".into(),
+            ))
+    }
+
+    /// Sends the request and saves its observations to a Parquet file at `path`. The file
+    /// is written next to `path` first and renamed into place once complete.
+    pub async fn save_parquet(&self, path: impl AsRef<Path>) -> Result<SdmxResponse<DataSets>> {
+        let path = path.as_ref();
+        let response = self.send().await?;
+        let dataflow_identifier = self.versioned_dataflow_identifier(&response).await?;
+
+        let mut temp = path.as_os_str().to_owned();
+        temp.push(format!(
+            ".{}-{}.tmp",
+            process::id(),
+            TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        let written = File::create(&temp)
+            .map_err(ErrorCode::from)
+            .and_then(|file| write_parquet(file, &response, &dataflow_identifier))
+            .and_then(|()| fs::rename(&temp, path).map_err(ErrorCode::from));
+
+        if written.is_err() {
+            let _ = fs::remove_file(&temp);
+        }
+
+        written.map(|()| response)
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, NaiveDate};
+use polars::prelude::{
+    CategoricalOrdering, Column, DataFrame, DataType, IntoColumn, NamedFrom, Series,
+};
+
+use crate::{
+    config::Config,
+    error_code::ErrorCode,
+    exporters,
+    models::{
+        derived::{data_sets::DataSets, sdmx_response::SdmxResponse},
+        typed::period::Period,
+    },
+    result::Result,
+};
+
+/// Days from 1 January of year 1 to the Unix epoch.
+const UNIX_EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+fn categorical(name: &str, codes: &[Option<&str>]) -> Result<Column> {
+    let series = Series::new(name.into(), codes)
+        .cast(&DataType::Categorical(None, CategoricalOrdering::Physical))?;
+
+    Ok(series.into_column())
+}
+
+fn dates(name: &str, dates: impl IntoIterator<Item = Option<NaiveDate>>) -> Result<Column> {
+    let days = dates
+        .into_iter()
+        .map(|date| Some(date?.num_days_from_ce() - UNIX_EPOCH_DAYS_FROM_CE))
+        .collect::<Vec<_>>();
+
+    Ok(Series::new(name.into(), days)
+        .cast(&DataType::Date)?
+        .into_column())
+}
+
+/// Converts the observations of `data_sets` into a long data frame with one row each.
+///
+/// Dimensions and attributes become categorical code columns named by their id,
+/// `TIME_PERIOD` the first day of each period as a date, and `OBS_VALUE` a float.
+pub fn long_data_frame(data_sets: &DataSets) -> Result<DataFrame> {
+    let structure = data_sets
+        .structure
+        .as_ref()
+        .ok_or(ErrorCode::MissingExpectedOptionalField("structure".into()))?;
+
+    let dimensions = exporters::dimensions(structure)
+        .map(|(id, ..)| id)
+        .collect::<Vec<_>>();
+    let attributes = exporters::attributes(structure)
+        .map(|(id, ..)| id)
+        .collect::<Vec<_>>();
+
+    let mut dimension_codes = vec![Vec::new(); dimensions.len()];
+    let mut attribute_codes = vec![Vec::new(); attributes.len()];
+    let mut time_periods = Vec::new();
+    let mut obs_values = Vec::new();
+
+    for observation in data_sets.observations()? {
+        for (codes, id) in dimension_codes.iter_mut().zip(&dimensions) {
+            codes.push(observation.dimension(id).map(|d| d.code));
+        }
+        for (codes, id) in attribute_codes.iter_mut().zip(&attributes) {
+            codes.push(observation.attribute(id).map(|a| a.code));
+        }
+
+        let time_period = match observation.time_period {
+            Some(time_period) => Some(Period::parse(time_period)?.first_date()?),
+            None => None,
+        };
+
+        time_periods.push(time_period);
+        obs_values.push(observation.value);
+    }
+
+    let mut columns = Vec::new();
+
+    for (id, codes) in dimensions.iter().zip(&dimension_codes) {
+        columns.push(categorical(id, codes)?);
+    }
+
+    columns.push(dates(Config::TIME_PERIOD_DIMENSION, time_periods)?);
+    columns.push(Column::new(Config::PRIMARY_MEASURE.into(), obs_values));
+
+    for (id, codes) in attributes.iter().zip(&attribute_codes) {
+        columns.push(categorical(id, codes)?);
+    }
+
+    Ok(DataFrame::new(columns)?)
+}
+
+/// Converts the observations of `data_sets` into a wide data frame with a `TIME_PERIOD`
+/// date column, holding the first day of each period, and a float column per series named
+/// by its series key, such as `1.10001.10.8.Q`. Fails when the series have different
+/// frequencies, as their periods would share rows.
+pub fn wide_data_frame(data_sets: &DataSets) -> Result<DataFrame> {
+    let time_series = data_sets.time_series()?;
+
+    let mut frequency = None;
+    let mut rows = BTreeMap::new();
+    for series in &time_series {
+        for observation in series.observations() {
+            let period_frequency = observation.period.frequency();
+
+            let first = *frequency.get_or_insert(period_frequency);
+            if first != period_frequency {
+                return Err(ErrorCode::MixedFrequencies(
+                    first.to_string().into(),
+                    period_frequency.to_string().into(),
+                ));
+            }
+
+            rows.insert(observation.period.first_date()?, 0);
+        }
+    }
+    for (row, index) in rows.values_mut().zip(0..) {
+        *row = index;
+    }
+
+    let mut columns = vec![dates(
+        Config::TIME_PERIOD_DIMENSION,
+        rows.keys().copied().map(Some),
+    )?];
+
+    for series in &time_series {
+        let mut values = vec![None; rows.len()];
+
+        for observation in series.observations() {
+            values[rows[&observation.period.first_date()?]] = observation.value;
+        }
+
+        columns.push(Column::new(series.key().as_ref().into(), values));
+    }
+
+    Ok(DataFrame::new(columns)?)
+}
+
+impl DataSets {
+    pub fn to_data_frame(&self) -> Result<DataFrame> {
+        long_data_frame(self)
+    }
+
+    pub fn to_wide_data_frame(&self) -> Result<DataFrame> {
+        wide_data_frame(self)
+    }
+}
+
+impl SdmxResponse<DataSets> {
+    pub fn to_data_frame(&self) -> Result<DataFrame> {
+        long_data_frame(&self.data)
+    }
+
+    pub fn to_wide_data_frame(&self) -> Result<DataFrame> {
+        wide_data_frame(&self.data)
+    }
+}
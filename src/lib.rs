@@ -1,5 +1,8 @@
 pub mod builders;
 pub mod config;
 pub mod error_code;
+pub mod exporters;
 pub mod models;
+pub mod parsers;
 pub mod result;
+pub mod transport;
@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+
+use super::{data::Data, data_point::DataPoint, structure::Structure, value::Value};
+
+impl Structure {
+    /// Resolves the attribute indexes of a series to the values of `attributes.series`,
+    /// keyed by attribute id. Attributes without a value are left out.
+    pub fn series_attributes<'a>(&'a self, data: &Data) -> HashMap<&'a str, &'a Value> {
+        self.series_attribute_values(data).collect()
+    }
+
+    /// Resolves the attribute indexes following the measure values in an observation
+    /// array to the values of `attributes.observation`, keyed by attribute id.
+    /// Attributes without a value are left out.
+    pub fn observation_attributes<'a>(
+        &'a self,
+        observation: &[Option<DataPoint>],
+    ) -> HashMap<&'a str, &'a Value> {
+        self.observation_attribute_values(observation).collect()
+    }
+
+    /// How many measure values lead an observation array: one per measure, or the primary
+    /// measure alone when the structure has no measures.
+    fn measure_count(&self) -> usize {
+        self.measures
+            .as_ref()
+            .map_or(1, |measures| measures.observation.len())
+    }
+
+    /// Like `series_attributes`, in structure order.
+    pub(crate) fn series_attribute_values<'a, 'b>(
+        &'a self,
+        data: &'b Data,
+    ) -> impl Iterator<Item = (&'a str, &'a Value)> + 'b
+    where
+        'a: 'b,
+    {
+        self.attributes
+            .series
+            .iter()
+            .zip(data.attributes.iter())
+            .filter_map(|(attribute, index)| {
+                let index = usize::try_from((*index)?).ok()?;
+                Some((attribute.id.as_ref(), attribute.values.get(index)?))
+            })
+    }
+
+    /// Like `observation_attributes`, in structure order.
+    pub(crate) fn observation_attribute_values<'a, 'b>(
+        &'a self,
+        observation: &'b [Option<DataPoint>],
+    ) -> impl Iterator<Item = (&'a str, &'a Value)> + 'b
+    where
+        'a: 'b,
+    {
+        self.attributes
+            .observation
+            .iter()
+            .zip(observation.iter().skip(self.measure_count()))
+            .filter_map(|(attribute, index)| {
+                let index = usize::try_from(index.as_ref()?.as_i64()?).ok()?;
+                Some((attribute.id.as_ref(), attribute.values.get(index)?))
+            })
+    }
+}
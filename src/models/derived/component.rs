@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Component {
+    pub id: Box<str>,
+    pub position: Option<usize>,
+    #[serde(rename = "type")]
+    pub _type: Option<Box<str>>,
+    pub concept_identity: Option<Box<str>>,
+    pub local_representation: Option<LocalRepresentation>,
+    pub assignment_status: Option<Box<str>>,
+    pub attribute_relationship: Option<AttributeRelationship>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalRepresentation {
+    pub enumeration: Option<Box<str>>,
+    pub text_format: Option<TextFormat>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextFormat {
+    pub text_type: Option<Box<str>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttributeRelationship {
+    pub dimensions: Option<Box<[Box<str>]>>,
+    pub primary_measure: Option<Box<str>>,
+}
@@ -6,7 +6,9 @@ use serde::{Deserialize, Serialize};
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Data {
+    #[serde(default)]
     pub attributes: Box<[Option<i64>]>,
+    #[serde(default)]
     pub annotations: Box<[Annotation]>,
     pub observations: HashMap<Box<str>, Box<[Option<DataPoint>]>>,
 }
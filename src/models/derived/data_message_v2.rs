@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{error_code::ErrorCode, result::Result};
+
+use super::{
+    data_sets::{DataSet, DataSets},
+    meta::Meta,
+    sdmx_response::SdmxResponse,
+    structure::Structure,
+};
+
+/// An SDMX-JSON 2.0 data message, where structures and data sets are siblings and
+/// each data set refers to its structure by index.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataMessageV2 {
+    pub meta: Meta,
+    pub data: DataMessageV2Data,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataMessageV2Data {
+    #[serde(default)]
+    pub structures: Box<[Structure]>,
+    #[serde(default)]
+    pub data_sets: Box<[DataSet]>,
+}
+
+impl TryFrom<DataMessageV2> for SdmxResponse<DataSets> {
+    type Error = ErrorCode;
+
+    /// Normalises into the SDMX-JSON 1.0 layout, with the structure of the first data
+    /// set as the message's structure, keeping every structure for data sets that refer
+    /// to others.
+    fn try_from(message: DataMessageV2) -> Result<Self> {
+        let DataMessageV2Data {
+            structures,
+            data_sets,
+        } = message.data;
+
+        let indices = data_sets
+            .iter()
+            .map(|data_set| data_set.structure.unwrap_or_default());
+
+        if let Some(missing) = indices.clone().find(|index| *index >= structures.len()) {
+            return Err(ErrorCode::UnsupportedDataMessage(
+                format!("data set refers to missing structure {}", missing).into(),
+            ));
+        }
+
+        let structure = structures
+            .get(indices.clone().next().unwrap_or_default())
+            .cloned();
+
+        Ok(SdmxResponse {
+            data: DataSets {
+                data_sets,
+                structure,
+                structures,
+            },
+            meta: message.meta,
+            structure: None,
+        })
+    }
+}
@@ -1,49 +1,237 @@
 use std::fmt;
 
+#[cfg(feature = "decimal")]
+use bigdecimal::BigDecimal;
 use serde::{
-    de::{self, Visitor},
+    de::{self, MapAccess, Visitor},
     Deserialize, Deserializer, Serialize,
 };
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+use crate::models::typed::missing_value::MissingValue;
+
+/// An observation value in the lexical form it was received in, with JSON numbers kept
+/// exactly as written and JSON `null` as `null`.
+#[derive(Default, Debug, Clone, Serialize)]
 pub struct DataPoint(Box<str>);
 
-impl<'de> Deserialize<'de> for DataPoint {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+impl DataPoint {
+    pub fn lexical(&self) -> &str {
+        &self.0
+    }
+
+    /// The missing value marker this data point holds, if any.
+    pub fn missing(&self) -> Option<MissingValue> {
+        MissingValue::from_lexical(&self.0)
+    }
+
+    pub fn is_missing(&self) -> bool {
+        self.missing().is_some()
+    }
+
+    /// The value as a number, or `None` when it is missing or not numeric.
+    pub fn as_f64(&self) -> Option<f64> {
+        if self.is_missing() {
+            return None;
+        }
+
+        self.0
+            .trim()
+            .parse()
+            .ok()
+            .filter(|value: &f64| !value.is_nan())
+    }
+
+    /// The value as an integer, or `None` when it is missing or not an integer.
+    pub fn as_i64(&self) -> Option<i64> {
+        if self.is_missing() {
+            return None;
+        }
+
+        self.0.trim().parse().ok()
+    }
+
+    /// The value as a boolean, or `None` when it is missing or not a boolean.
+    pub fn as_bool(&self) -> Option<bool> {
+        if self.is_missing() {
+            return None;
+        }
+
+        self.0.trim().parse().ok()
+    }
+
+    /// The value as an exact decimal, or `None` when it is missing or not numeric.
+    #[cfg(feature = "decimal")]
+    pub fn as_decimal(&self) -> Option<BigDecimal> {
+        if self.is_missing() {
+            return None;
+        }
+
+        self.0.trim().parse().ok()
+    }
+}
+
+/// A decimal number as its sign, significant digits and the position of the decimal
+/// point after the first of them, such as `(false, "129", 3)` for both `129.0` and
+/// `1.29e2`. Zero has no sign or digits.
+fn canonical_number(str: &str) -> Option<(bool, Box<str>, i64)> {
+    let str = str.trim();
+    let (negative, unsigned) = match str.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, str.strip_prefix('+').unwrap_or(str)),
+    };
+    let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i64>().ok()?),
+        None => (unsigned, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let digits = [integer, fraction].concat();
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let trimmed = digits.trim_start_matches('0');
+    let point = integer.len() as i64 - (digits.len() - trimmed.len()) as i64 + exponent;
+    let significant = trimmed.trim_end_matches('0');
+
+    if significant.is_empty() {
+        return Some((false, Box::default(), 0));
+    }
+
+    Some((negative, significant.into(), point))
+}
+
+/// Data points holding the same number are equal however they were written, such as
+/// `129.0` and `1.29e2`. Other values are equal when written the same.
+impl PartialEq for DataPoint {
+    fn eq(&self, other: &Self) -> bool {
+        match (canonical_number(&self.0), canonical_number(&other.0)) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.0 == other.0,
+        }
+    }
+}
+
+impl AsRef<str> for DataPoint {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for DataPoint {
+    fn from(data: &str) -> Self {
+        DataPoint(data.into())
+    }
+}
+
+impl fmt::Display for DataPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+const EXPECTING: &str = "a data point as a number, a boolean, a string, or null";
+
+/// The newtype name serde_json answers with the raw JSON text of a value, as a map of
+/// this name to the text. This is how `serde_json::value::RawValue` is read.
+const RAW_VALUE_TOKEN: &str = "$serde_json::private::RawValue";
+
+struct DataPointVisitor;
+
+impl DataPointVisitor {
+    /// Keeps the JSON text of the value as written, such as `1.50` or `1e3`, unescaping
+    /// strings.
+    fn raw<E>(text: &str) -> Result<DataPoint, E>
     where
-        D: Deserializer<'de>,
+        E: de::Error,
     {
-        struct DataPointVisitor;
-
-        impl<'de> Visitor<'de> for DataPointVisitor {
-            type Value = DataPoint;
+        match text.as_bytes().first() {
+            Some(b'"') => serde_json::from_str::<Box<str>>(text)
+                .map(DataPoint)
+                .map_err(de::Error::custom),
+            Some(b'{') => Err(de::Error::invalid_type(de::Unexpected::Map, &EXPECTING)),
+            Some(b'[') => Err(de::Error::invalid_type(de::Unexpected::Seq, &EXPECTING)),
+            _ => Ok(DataPoint(text.into())),
+        }
+    }
+}
 
-            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                f.write_str("a data point as a float, an integer, or a string")
-            }
+impl<'de> Visitor<'de> for DataPointVisitor {
+    type Value = DataPoint;
 
-            fn visit_f64<E>(self, data: f64) -> Result<Self::Value, E>
-            where
-                E: de::Error,
-            {
-                Ok(DataPoint(data.to_string().into()))
-            }
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(EXPECTING)
+    }
 
-            fn visit_u64<E>(self, data: u64) -> Result<Self::Value, E>
-            where
-                E: de::Error,
-            {
-                Ok(DataPoint(data.to_string().into()))
-            }
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<DataPoint, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
 
-            fn visit_str<E>(self, data: &str) -> Result<Self::Value, E>
-            where
-                E: de::Error,
-            {
-                Ok(DataPoint(data.into()))
+    fn visit_map<A>(self, mut map: A) -> Result<DataPoint, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        match map.next_key::<Box<str>>()? {
+            Some(key) if key.as_ref() == RAW_VALUE_TOKEN => {
+                Self::raw(&map.next_value::<Box<str>>()?)
             }
+            _ => Err(de::Error::invalid_type(de::Unexpected::Map, &self)),
         }
+    }
+
+    fn visit_seq<A>(self, _: A) -> Result<DataPoint, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        Err(de::Error::invalid_type(de::Unexpected::Seq, &self))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<DataPoint, E> {
+        Ok(DataPoint(value.into()))
+    }
 
-        deserializer.deserialize_any(DataPointVisitor)
+    fn visit_bool<E>(self, value: bool) -> Result<DataPoint, E> {
+        Ok(DataPoint(value.to_string().into()))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<DataPoint, E> {
+        Ok(DataPoint(value.to_string().into()))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<DataPoint, E> {
+        Ok(DataPoint(value.to_string().into()))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<DataPoint, E> {
+        Ok(DataPoint(value.to_string().into()))
+    }
+
+    fn visit_unit<E>(self) -> Result<DataPoint, E> {
+        Ok(DataPoint("null".into()))
+    }
+
+    fn visit_none<E>(self) -> Result<DataPoint, E> {
+        Ok(DataPoint("null".into()))
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<DataPoint, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+/// Reads JSON through its raw text, keeping numbers exactly as written. Other formats
+/// are read from their strings, numbers, booleans and nulls.
+impl<'de> Deserialize<'de> for DataPoint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(RAW_VALUE_TOKEN, DataPointVisitor)
     }
 }
@@ -2,20 +2,85 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::{error_code::ErrorCode, models::typed::version::Version, result::Result};
+
 use super::{data::Data, link::Link, structure::Structure};
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataSets {
     pub data_sets: Box<[DataSet]>,
+    /// The structure of the first data set.
     pub structure: Option<Structure>,
+    /// Every structure of an SDMX-JSON 2.0 message, which its data sets refer to by index.
+    #[serde(default, skip_serializing_if = "<[_]>::is_empty")]
+    pub structures: Box<[Structure]>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataSet {
+    /// Index into the structures of an SDMX-JSON 2.0 message.
+    #[serde(default)]
+    pub structure: Option<usize>,
     pub action: Box<str>,
+    #[serde(default)]
     pub links: Box<[Link]>,
+    #[serde(default)]
     pub annotations: Vec<i64>,
     pub series: HashMap<Box<str>, Data>,
 }
+
+impl DataSets {
+    /// The structure `data_set` refers to in an SDMX-JSON 2.0 message, or otherwise the
+    /// message's single structure.
+    pub fn structure_of(&self, data_set: &DataSet) -> Option<&Structure> {
+        if self.structures.is_empty() {
+            self.structure.as_ref()
+        } else {
+            self.structures.get(data_set.structure.unwrap_or_default())
+        }
+    }
+
+    /// The data sets grouped by the structure they refer to, in order of first appearance.
+    pub(crate) fn by_structure(&self) -> Result<Vec<(&Structure, Vec<&DataSet>)>> {
+        let mut groups = Vec::<(&Structure, Vec<&DataSet>)>::new();
+
+        for data_set in self.data_sets.iter() {
+            let structure = self
+                .structure_of(data_set)
+                .ok_or(ErrorCode::MissingExpectedOptionalField("structure".into()))?;
+
+            match groups.iter_mut().find(|(s, _)| std::ptr::eq(*s, structure)) {
+                Some((_, data_sets)) => data_sets.push(data_set),
+                None => groups.push((structure, vec![data_set])),
+            }
+        }
+
+        Ok(groups)
+    }
+
+    /// The version of the dataflow the message was prepared from, read from the `dataflow`
+    /// links of the structure or the data sets, such as
+    /// `urn:sdmx:org.sdmx.infomodel.datastructure.Dataflow=ABS:CPI(1.1.0)`.
+    pub fn dataflow_version(&self) -> Option<Version> {
+        let structure_links = self.structure.iter().flat_map(|s| s.links.iter());
+        let data_set_links = self.data_sets.iter().flat_map(|d| d.links.iter());
+
+        structure_links
+            .chain(data_set_links)
+            .filter(|link| link.rel.as_ref() == "dataflow")
+            .find_map(link_version)
+    }
+}
+
+/// The version in a dataflow urn, or the last path segment of a dataflow href.
+fn link_version(link: &Link) -> Option<Version> {
+    let version = match (&link.urn, &link.href) {
+        (Some(urn), _) => urn.rsplit_once('(')?.1.strip_suffix(')')?,
+        (None, Some(href)) => href.trim_end_matches('/').rsplit('/').next()?,
+        (None, None) => return None,
+    };
+
+    Version::try_from(Box::<str>::from(version)).ok()
+}
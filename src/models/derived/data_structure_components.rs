@@ -1,5 +1,36 @@
 use serde::{Deserialize, Serialize};
 
+use super::component::Component;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataStructureComponents {
+    pub attribute_list: Option<AttributeList>,
+    pub dimension_list: DimensionList,
+    pub measure_list: Option<MeasureList>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttributeList {
+    pub id: Box<str>,
+    #[serde(default)]
+    pub attributes: Box<[Component]>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DimensionList {
+    pub id: Box<str>,
+    #[serde(default)]
+    pub dimensions: Box<[Component]>,
+    #[serde(default)]
+    pub time_dimensions: Box<[Component]>,
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct DataStructureComponents {}
+pub struct MeasureList {
+    pub id: Box<str>,
+    pub primary_measure: Component,
+}
@@ -5,6 +5,7 @@ use super::{observation::Observation, series::Series, value::Value};
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Dimensions {
+    #[serde(default, alias = "dataSet")]
     pub dataset: Box<[Value]>,
     pub series: Box<[Series]>,
     pub observation: Box<[Observation]>,
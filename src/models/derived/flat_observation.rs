@@ -0,0 +1,229 @@
+use std::vec;
+
+#[cfg(feature = "decimal")]
+use bigdecimal::BigDecimal;
+
+use crate::{config::Config, error_code::ErrorCode, result::Result};
+
+use super::{
+    data::Data,
+    data_point::DataPoint,
+    data_sets::{DataSet, DataSets},
+    sdmx_response::SdmxResponse,
+    structure::Structure,
+    value::Value,
+};
+
+/// A dimension or attribute value resolved against the structure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ResolvedComponent<'a> {
+    pub id: &'a str,
+    pub code: &'a str,
+    pub label: &'a str,
+}
+
+/// One observation with its series key decoded into dimension codes and labels.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlatObservation<'a> {
+    pub series_key: &'a str,
+    pub dimensions: Box<[ResolvedComponent<'a>]>,
+    pub time_period: Option<&'a str>,
+    pub data_point: Option<&'a DataPoint>,
+    pub value: Option<f64>,
+    pub attributes: Box<[ResolvedComponent<'a>]>,
+}
+
+impl<'a> FlatObservation<'a> {
+    pub fn dimension(&self, id: &str) -> Option<&ResolvedComponent<'a>> {
+        self.dimensions.iter().find(|d| d.id == id)
+    }
+
+    pub fn attribute(&self, id: &str) -> Option<&ResolvedComponent<'a>> {
+        self.attributes.iter().find(|a| a.id == id)
+    }
+
+    /// The exact value of the observation, or `None` when it is missing.
+    #[cfg(feature = "decimal")]
+    pub fn decimal(&self) -> Option<BigDecimal> {
+        self.data_point?.as_decimal()
+    }
+}
+
+/// Parses a colon separated key of value indices, such as `0:1:0`.
+fn indices(key: &str) -> Vec<Option<usize>> {
+    key.split(':').map(|index| index.parse().ok()).collect()
+}
+
+fn component<'a>(id: &'a str, value: &'a Value) -> ResolvedComponent<'a> {
+    ResolvedComponent {
+        id,
+        code: &value.id,
+        label: &value.name,
+    }
+}
+
+fn resolve<'a>(id: &'a str, values: &'a [Value], index: usize) -> Option<ResolvedComponent<'a>> {
+    values.get(index).map(|value| component(id, value))
+}
+
+/// Iterates the observations of every data set in key order.
+pub struct FlatObservations<'a> {
+    structure: &'a Structure,
+    series: vec::IntoIter<(&'a str, &'a Data)>,
+    current: Option<Current<'a>>,
+}
+
+struct Current<'a> {
+    key: &'a str,
+    dimensions: Vec<ResolvedComponent<'a>>,
+    attributes: Vec<ResolvedComponent<'a>>,
+    observations: vec::IntoIter<(&'a str, &'a [Option<DataPoint>])>,
+}
+
+impl<'a> FlatObservations<'a> {
+    pub(crate) fn new<I>(structure: &'a Structure, data_sets: I) -> Self
+    where
+        I: IntoIterator<Item = &'a DataSet>,
+    {
+        let mut series = data_sets
+            .into_iter()
+            .flat_map(|data_set| data_set.series.iter())
+            .map(|(key, data)| (key.as_ref(), data))
+            .collect::<Vec<_>>();
+
+        series.sort_by_cached_key(|(key, _)| indices(key));
+
+        Self {
+            structure,
+            series: series.into_iter(),
+            current: None,
+        }
+    }
+
+    /// Iterates the observations of a single series.
+    pub(crate) fn series(structure: &'a Structure, key: &'a str, data: &'a Data) -> Self {
+        Self {
+            structure,
+            series: vec![(key, data)].into_iter(),
+            current: None,
+        }
+    }
+
+    fn start_series(&self, key: &'a str, data: &'a Data) -> Current<'a> {
+        let structure = self.structure;
+
+        let dimensions = structure
+            .dimensions
+            .series
+            .iter()
+            .zip(indices(key))
+            .filter_map(|(dimension, index)| resolve(&dimension.id, &dimension.values, index?))
+            .collect();
+
+        let attributes = structure
+            .series_attribute_values(data)
+            .map(|(id, value)| component(id, value))
+            .collect();
+
+        let mut observations = data
+            .observations
+            .iter()
+            .map(|(key, values)| (key.as_ref(), values.as_ref()))
+            .collect::<Vec<_>>();
+
+        observations.sort_by_cached_key(|(key, _)| indices(key));
+
+        Current {
+            key,
+            dimensions,
+            attributes,
+            observations: observations.into_iter(),
+        }
+    }
+
+    fn observation(
+        &self,
+        current: &Current<'a>,
+        key: &'a str,
+        values: &'a [Option<DataPoint>],
+    ) -> FlatObservation<'a> {
+        let structure = self.structure;
+        let mut dimensions = current.dimensions.clone();
+        let mut time_period = None;
+
+        for (dimension, index) in structure.dimensions.observation.iter().zip(indices(key)) {
+            let Some(resolved) = index.and_then(|i| resolve(&dimension.id, &dimension.values, i))
+            else {
+                continue;
+            };
+
+            if dimension.id.as_ref() == Config::TIME_PERIOD_DIMENSION {
+                time_period = Some(resolved.code);
+            } else {
+                dimensions.push(resolved);
+            }
+        }
+
+        let mut attributes = current.attributes.clone();
+        attributes.extend(
+            structure
+                .observation_attribute_values(values)
+                .map(|(id, value)| component(id, value)),
+        );
+
+        let data_point = values.first().and_then(Option::as_ref);
+
+        FlatObservation {
+            series_key: current.key,
+            dimensions: dimensions.into(),
+            time_period,
+            data_point,
+            value: data_point.and_then(DataPoint::as_f64),
+            attributes: attributes.into(),
+        }
+    }
+}
+
+impl<'a> Iterator for FlatObservations<'a> {
+    type Item = FlatObservation<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(current) = &mut self.current {
+                if let Some((key, values)) = current.observations.next() {
+                    let current = self.current.as_ref()?;
+                    return Some(self.observation(current, key, values));
+                }
+            }
+
+            let (key, data) = self.series.next()?;
+            self.current = Some(self.start_series(key, data));
+        }
+    }
+}
+
+impl DataSets {
+    /// Iterates every observation with its dimensions and attributes resolved against
+    /// `structure`, ordered by series key and then observation key. Fails when the data
+    /// sets refer to more than one structure, which `time_series` reads instead.
+    pub fn observations(&self) -> Result<FlatObservations<'_>> {
+        let structure = self
+            .structure
+            .as_ref()
+            .ok_or(ErrorCode::MissingExpectedOptionalField("structure".into()))?;
+
+        if self.by_structure()?.len() > 1 {
+            return Err(ErrorCode::UnsupportedDataMessage(
+                "data sets refer to more than one structure".into(),
+            ));
+        }
+
+        Ok(FlatObservations::new(structure, self.data_sets.iter()))
+    }
+}
+
+impl SdmxResponse<DataSets> {
+    pub fn observations(&self) -> Result<FlatObservations<'_>> {
+        self.data.observations()
+    }
+}
@@ -4,5 +4,7 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "camelCase")]
 pub struct Link {
     pub href: Option<Box<str>>,
+    #[serde(default)]
+    pub urn: Option<Box<str>>,
     pub rel: Box<str>,
 }
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use super::observation::Observation;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Measures {
+    pub observation: Box<[Observation]>,
+}
@@ -11,5 +11,6 @@ pub struct Meta {
     pub prepared: Box<str>,
     pub test: bool,
     pub sender: Sender,
+    #[serde(alias = "receivers")]
     pub receiver: Option<Box<[Receiver]>>,
 }
@@ -6,5 +6,10 @@ use super::meta_data_sets::MetaDataSet;
 #[serde(rename_all = "camelCase")]
 pub struct MetaDataMap {
     pub dataflows: Option<Box<[MetaDataSet]>>,
+    #[serde(alias = "dataConstraints")]
     pub content_constraints: Option<Box<[MetaDataSet]>>,
+    pub data_structures: Option<Box<[MetaDataSet]>>,
+    pub codelists: Option<Box<[MetaDataSet]>>,
+    pub concept_schemes: Option<Box<[MetaDataSet]>>,
+    pub category_schemes: Option<Box<[MetaDataSet]>>,
 }
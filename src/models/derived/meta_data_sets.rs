@@ -3,8 +3,10 @@ use serde::{Deserialize, Serialize};
 use crate::models::typed::version::Version;
 
 use super::{
-    annotation::Annotation, concept::Concept, constraint_attachment::ConstraintAttachment,
-    cube_region::CubeRegion, descriptions::Descriptions, link::Link, names::Names,
+    annotation::Annotation, category::Category, code::Code, concept::Concept,
+    constraint_attachment::ConstraintAttachment, cube_region::CubeRegion,
+    data_structure_components::DataStructureComponents, descriptions::Descriptions, link::Link,
+    names::Names,
 };
 
 pub type MetaDataSets = Box<[MetaDataSet]>;
@@ -23,6 +25,9 @@ pub struct MetaDataSet {
     pub names: Names,
     pub is_partial: Option<bool>,
     pub concepts: Option<Box<[Concept]>>,
+    pub codes: Option<Box<[Code]>>,
+    pub categories: Option<Box<[Category]>>,
+    pub data_structure_components: Option<DataStructureComponents>,
     pub description: Option<Box<str>>,
     pub descriptions: Option<Descriptions>,
     pub annotations: Option<Box<[Annotation]>>,
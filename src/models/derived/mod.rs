@@ -1,17 +1,23 @@
 pub mod annotation;
+pub mod attribute_values;
 pub mod attributes;
 pub mod category;
+pub mod code;
+pub mod component;
 pub mod concept;
 pub mod constraint_attachment;
 pub mod cube_region;
 pub mod data;
+pub mod data_message_v2;
 pub mod data_point;
 pub mod data_sets;
 pub mod data_structure_components;
 pub mod descriptions;
 pub mod dimensions;
+pub mod flat_observation;
 pub mod key_value;
 pub mod link;
+pub mod measures;
 pub mod meta;
 pub mod meta_data_map;
 pub mod meta_data_sets;
@@ -20,6 +26,7 @@ pub mod observation;
 pub mod period;
 pub mod receiver;
 pub mod relationship;
+pub mod sdmx_csv;
 pub mod sdmx_response;
 pub mod sender;
 pub mod series;
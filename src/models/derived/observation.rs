@@ -15,7 +15,9 @@ pub struct Observation {
     pub name: Box<str>,
     pub names: Names,
     pub key_position: Option<i64>,
+    #[serde(default)]
     pub roles: Box<[Box<str>]>,
+    #[serde(default)]
     pub values: Box<[Value]>,
     pub relationship: Option<Relationship>,
 }
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use super::data_point::DataPoint;
+
+/// The component columns of an SDMX-CSV message, in the order of the dataflow's data
+/// structure.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SdmxCsvColumns {
+    pub dimensions: Box<[Box<str>]>,
+    pub attributes: Box<[Box<str>]>,
+}
+
+impl SdmxCsvColumns {
+    pub fn dimension_position(&self, id: &str) -> Option<usize> {
+        self.dimensions.iter().position(|d| d.as_ref() == id)
+    }
+
+    pub fn attribute_position(&self, id: &str) -> Option<usize> {
+        self.attributes.iter().position(|a| a.as_ref() == id)
+    }
+}
+
+/// One observation of an SDMX-CSV message. `dimensions` and `attributes` line up with
+/// the columns of the same name in `SdmxCsvColumns`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SdmxCsvRow {
+    pub dataflow: Box<str>,
+    pub dimensions: Box<[Box<str>]>,
+    pub time_period: Option<Box<str>>,
+    pub obs_value: Option<DataPoint>,
+    pub attributes: Box<[Option<Box<str>>]>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SdmxCsv {
+    pub columns: SdmxCsvColumns,
+    pub rows: Box<[SdmxCsvRow]>,
+}
@@ -8,6 +8,7 @@ pub struct Series {
     pub id: Box<str>,
     pub name: Box<str>,
     pub names: Names,
+    #[serde(default)]
     pub roles: Box<[Box<str>]>,
     pub relationship: Option<Relationship>,
     pub values: Box<[Value]>,
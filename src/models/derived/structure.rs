@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use super::{
     annotation::Annotation, attributes::Attributes, descriptions::Descriptions,
-    dimensions::Dimensions, names::Names,
+    dimensions::Dimensions, link::Link, measures::Measures, names::Names,
 };
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -10,9 +10,16 @@ use super::{
 pub struct Structure {
     pub name: Box<str>,
     pub names: Names,
+    #[serde(default)]
     pub description: Box<str>,
+    #[serde(default)]
     pub descriptions: Descriptions,
     pub dimensions: Dimensions,
     pub attributes: Attributes,
+    #[serde(default)]
+    pub measures: Option<Measures>,
+    #[serde(default)]
     pub annotations: Box<[Annotation]>,
+    #[serde(default)]
+    pub links: Box<[Link]>,
 }
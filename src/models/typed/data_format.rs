@@ -0,0 +1,66 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+use crate::config::Config;
+
+use super::protocol_version::ProtocolVersion;
+
+/// The message format requested for data. SDMX-CSV is requested as version 1.0 with SDMX
+/// 2.1 and version 2.0 with SDMX 3.0. SDMX-ML formats require the `xml` feature and are
+/// always requested as SDMX-ML 2.1.
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    EnumIter,
+)]
+pub enum DataFormat {
+    #[default]
+    SdmxJson,
+    SdmxCsv,
+    #[cfg(feature = "xml")]
+    StructureSpecificXml,
+    #[cfg(feature = "xml")]
+    GenericXml,
+}
+
+impl DataFormat {
+    pub fn accept_headers(
+        &self,
+        protocol_version: ProtocolVersion,
+    ) -> &'static [(&'static str, &'static str)] {
+        match (self, protocol_version) {
+            (Self::SdmxJson, ProtocolVersion::Sdmx21) => &[Config::ACCEPT_DATA_JSON],
+            (Self::SdmxJson, ProtocolVersion::Sdmx30) => &[Config::ACCEPT_DATA_JSON_2_0],
+            (Self::SdmxCsv, ProtocolVersion::Sdmx21) => &[Config::ACCEPT_DATA_CSV],
+            (Self::SdmxCsv, ProtocolVersion::Sdmx30) => &[Config::ACCEPT_DATA_CSV_2_0],
+            #[cfg(feature = "xml")]
+            (Self::StructureSpecificXml, _) => &[Config::ACCEPT_DATA_STRUCTURE_SPECIFIC_XML],
+            #[cfg(feature = "xml")]
+            (Self::GenericXml, _) => &[Config::ACCEPT_DATA_GENERIC_XML],
+        }
+    }
+}
+
+impl Display for DataFormat {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::SdmxJson => write!(f, "sdmx-json"),
+            Self::SdmxCsv => write!(f, "sdmx-csv"),
+            #[cfg(feature = "xml")]
+            Self::StructureSpecificXml => write!(f, "structurespecificdata"),
+            #[cfg(feature = "xml")]
+            Self::GenericXml => write!(f, "genericdata"),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use std::io::{self, Read};
+
+use tokio::sync::mpsc;
+
+use crate::{
+    error_code::ErrorCode,
+    parsers::{sdmx_csv, sdmx_json},
+    result::Result,
+    transport::BodyStream,
+};
+
+use super::{data_format::DataFormat, time_series::TimeSeries};
+
+/// How many body chunks, and how many series, are buffered ahead of the reader.
+const CAPACITY: usize = 16;
+
+pub(crate) fn unsupported(format: DataFormat) -> ErrorCode {
+    ErrorCode::UnsupportedDataMessage(
+        format!("series are only streamed from sdmx-json and sdmx-csv, not {format}").into(),
+    )
+}
+
+/// Reads the chunks of a response body, received from the task reading the body, as a
+/// blocking `Read`.
+struct ChunkReader {
+    chunks: mpsc::Receiver<Result<Box<[u8]>>>,
+    chunk: Box<[u8]>,
+    position: usize,
+    /// The error the body failed with, kept so it is not flattened into an IO error.
+    error: Option<ErrorCode>,
+}
+
+impl Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position >= self.chunk.len() {
+            match self.chunks.blocking_recv() {
+                Some(Ok(chunk)) => {
+                    self.chunk = chunk;
+                    self.position = 0;
+                }
+                Some(Err(e)) => {
+                    let message = e.to_string();
+                    self.error = Some(e);
+                    return Err(io::Error::other(message));
+                }
+                None => return Ok(0),
+            }
+        }
+
+        let remaining = &self.chunk[self.position..];
+        let length = remaining.len().min(buf.len());
+        buf[..length].copy_from_slice(&remaining[..length]);
+        self.position += length;
+
+        Ok(length)
+    }
+}
+
+/// The series of a data response, read as its body arrives. Returned by
+/// `SdmxDataRequest::send_stream`.
+///
+/// The body is parsed on a blocking thread a few chunks ahead of the series handed out
+/// by `next`, so memory stays bounded however large the response is, as long as each
+/// SDMX-JSON data set comes after its structure, as described on
+/// `sdmx_json::read_series`. Dropping the stream stops reading the body.
+pub struct DataStream {
+    series: mpsc::Receiver<Result<TimeSeries>>,
+}
+
+impl DataStream {
+    pub(crate) fn spawn(mut body: Box<dyn BodyStream>, format: DataFormat) -> Self {
+        let (chunk_sender, chunks) = mpsc::channel(CAPACITY);
+        let (series_sender, series) = mpsc::channel(CAPACITY);
+
+        tokio::spawn(async move {
+            let mut empty = true;
+
+            loop {
+                let chunk = match body.chunk().await {
+                    Ok(Some(chunk)) => Ok(chunk),
+                    Ok(None) if empty => Err(ErrorCode::HttpEmptyResponse),
+                    Ok(None) => break,
+                    Err(e) => Err(e),
+                };
+                empty = false;
+
+                let failed = chunk.is_err();
+                if chunk_sender.send(chunk).await.is_err() || failed {
+                    break;
+                }
+            }
+        });
+
+        tokio::task::spawn_blocking(move || {
+            let mut reader = ChunkReader {
+                chunks,
+                chunk: Box::default(),
+                position: 0,
+                error: None,
+            };
+            let on_series = |series| {
+                series_sender
+                    .blocking_send(Ok(series))
+                    .map_err(|_| ErrorCode::StreamClosed)
+            };
+
+            let result = match format {
+                DataFormat::SdmxJson => sdmx_json::read_series(&mut reader, on_series),
+                DataFormat::SdmxCsv => sdmx_csv::read_series(&mut reader, on_series),
+                #[cfg(feature = "xml")]
+                _ => Err(unsupported(format)),
+            };
+
+            match (reader.error, result) {
+                (_, Err(ErrorCode::StreamClosed)) | (None, Ok(())) => {}
+                (Some(e), _) | (None, Err(e)) => {
+                    let _ = series_sender.blocking_send(Err(e));
+                }
+            }
+        });
+
+        Self { series }
+    }
+
+    /// The next series, or `None` once the response has been read in full. Reading
+    /// stops at the first error.
+    pub async fn next(&mut self) -> Option<Result<TimeSeries>> {
+        self.series.recv().await
+    }
+}
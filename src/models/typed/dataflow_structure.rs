@@ -0,0 +1,190 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    builders::sdmx_meta_request_builder::SdmxMetaRequestBuilder,
+    error_code::ErrorCode,
+    models::derived::{meta_data_map::MetaDataMap, meta_data_sets::MetaDataSet},
+    result::Result,
+};
+
+use super::{
+    dataflow_identifier::DataflowIdentifier, reference::Reference, sdmx_client::SdmxClient,
+    structure_type::StructureType,
+};
+
+/// Code names keyed by code id.
+pub type Codelist = HashMap<Box<str>, Box<str>>;
+
+/// The series dimensions of a dataflow in key order, with the codes its content
+/// constraint allows for each dimension and the codelist each dimension is drawn from.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct DataflowStructure {
+    dimensions: Box<[Box<str>]>,
+    codes: HashMap<Box<str>, HashSet<Box<str>>>,
+    codelists: HashMap<Box<str>, Codelist>,
+}
+
+impl DataflowStructure {
+    pub fn new(
+        dimensions: Box<[Box<str>]>,
+        codes: HashMap<Box<str>, HashSet<Box<str>>>,
+        codelists: HashMap<Box<str>, Codelist>,
+    ) -> Self {
+        Self {
+            dimensions,
+            codes,
+            codelists,
+        }
+    }
+
+    /// Fetches the dataflow with its descendants, the data structure and its codelists.
+    /// The content constraint is fetched separately when it is not among them.
+    pub async fn fetch(client: &SdmxClient, id: &DataflowIdentifier) -> Result<Self> {
+        let data = Self::fetch_meta(client, id, &Reference::Descendants).await?;
+
+        let constraints = match data.content_constraints {
+            Some(_) => None,
+            None => Some(
+                Self::fetch_meta(
+                    client,
+                    id,
+                    &Reference::StructureType(StructureType::ContentConstraint),
+                )
+                .await?,
+            ),
+        };
+
+        let key_values = constraints
+            .as_ref()
+            .unwrap_or(&data)
+            .content_constraints
+            .as_ref()
+            .ok_or(ErrorCode::MissingExpectedOptionalField(
+                "content constraints".into(),
+            ))?
+            .first()
+            .ok_or(ErrorCode::MissingExpectedValueOnField(
+                "content constraint".into(),
+            ))?
+            .cube_regions
+            .as_ref()
+            .ok_or(ErrorCode::MissingExpectedOptionalField(
+                "cube regions".into(),
+            ))?
+            .first()
+            .ok_or(ErrorCode::MissingExpectedValueOnField("cub regions".into()))?
+            .key_values
+            .as_ref();
+
+        let mut dimensions = Vec::new();
+        let mut codes = HashMap::new();
+
+        for kv in key_values.iter() {
+            let values_set: HashSet<Box<str>> = kv.values.clone().into_vec().into_iter().collect();
+
+            dimensions.push(kv.id.clone());
+            codes.insert(kv.id.clone(), values_set);
+        }
+
+        let codelists = Self::codelists(&data);
+
+        Ok(Self::new(dimensions.into(), codes, codelists))
+    }
+
+    async fn fetch_meta(
+        client: &SdmxClient,
+        id: &DataflowIdentifier,
+        reference: &Reference,
+    ) -> Result<MetaDataMap> {
+        let mut builder = SdmxMetaRequestBuilder::new(&StructureType::DataFlow)
+            .client(client)
+            .structure_id(id.structure_id())
+            .reference(reference);
+
+        if let Some(agency_id) = id.agency_id() {
+            builder = builder.agency_id(agency_id)
+        }
+        if let Some(version) = id.version() {
+            builder = builder.structure_version(version)
+        }
+
+        Ok(builder.build().send().await?.data)
+    }
+
+    /// The codelist of each dimension of the data structure in `data`, found through the
+    /// codelist urn of the dimension's enumeration.
+    fn codelists(data: &MetaDataMap) -> HashMap<Box<str>, Codelist> {
+        let (Some(data_structure), Some(codelists)) = (
+            data.data_structures.as_ref().and_then(|d| d.first()),
+            data.codelists.as_ref(),
+        ) else {
+            return HashMap::new();
+        };
+
+        let Some(components) = &data_structure.data_structure_components else {
+            return HashMap::new();
+        };
+
+        components
+            .dimension_list
+            .dimensions
+            .iter()
+            .filter_map(|dimension| {
+                let urn = dimension
+                    .local_representation
+                    .as_ref()?
+                    .enumeration
+                    .as_ref()?;
+                let codelist = codelists.iter().find(|c| Self::is_referenced_by(c, urn))?;
+                let codes = codelist
+                    .codes
+                    .iter()
+                    .flatten()
+                    .map(|code| (code.id.clone(), code.name.clone()))
+                    .collect();
+
+                Some((dimension.id.clone(), codes))
+            })
+            .collect()
+    }
+
+    /// Whether `urn`, such as `...Codelist=ABS:CL_FREQ(1.0.0)`, refers to `codelist`.
+    fn is_referenced_by(codelist: &MetaDataSet, urn: &str) -> bool {
+        let reference = urn.rsplit('=').next().unwrap_or(urn);
+        let (agency_id, rest) = reference.split_once(':').unwrap_or(("", reference));
+        let id = rest.split('(').next().unwrap_or(rest);
+
+        codelist.id.as_ref() == id
+            && (agency_id.is_empty() || codelist.agency_id.as_ref() == agency_id)
+    }
+
+    pub fn dimensions(&self) -> &[Box<str>] {
+        &self.dimensions
+    }
+
+    pub fn position(&self, dimension: &str) -> Option<usize> {
+        self.dimensions.iter().position(|d| d.as_ref() == dimension)
+    }
+
+    pub fn codes(&self, dimension: &str) -> Option<&HashSet<Box<str>>> {
+        self.codes.get(dimension)
+    }
+
+    pub fn all_codes(&self) -> &HashMap<Box<str>, HashSet<Box<str>>> {
+        &self.codes
+    }
+
+    /// The codelist of `dimension`, when the structure message included it.
+    pub fn codelist(&self, dimension: &str) -> Option<&Codelist> {
+        self.codelists.get(dimension)
+    }
+
+    /// The name of `code` in the codelist of `dimension`.
+    pub fn label(&self, dimension: &str, code: &str) -> Option<&str> {
+        self.codelist(dimension)?.get(code).map(AsRef::as_ref)
+    }
+
+    pub fn all_codelists(&self) -> &HashMap<Box<str>, Codelist> {
+        &self.codelists
+    }
+}
@@ -18,15 +18,39 @@ impl DataKey {
             inner: "all".into(),
         }
     }
+
+    /// The codes at each key position, with an empty position for an unfiltered
+    /// dimension and `+` between alternative codes. `None` when the key has no filter.
+    pub fn positions(&self) -> Option<Vec<&str>> {
+        if *self == Self::no_filter() {
+            return None;
+        }
+
+        Some(self.inner.split('.').collect())
+    }
+
+    /// The key as an SDMX 3.0 path segment, with `*` for an unfiltered position and `,`
+    /// between alternative codes.
+    pub fn sdmx_3_0_key(&self) -> String {
+        let Some(positions) = self.positions() else {
+            return Config::WILDCARD.into();
+        };
+
+        positions
+            .into_iter()
+            .map(|codes| match codes {
+                "" => Config::WILDCARD.into(),
+                codes => codes.replace('+', ","),
+            })
+            .collect::<Vec<_>>()
+            .join(".")
+    }
 }
 
 impl PartialEq for DataKey {
     fn eq(&self, other: &Self) -> bool {
         self.inner == other.inner
     }
-    fn ne(&self, other: &Self) -> bool {
-        self.inner != other.inner
-    }
 }
 
 impl TryFrom<&str> for DataKey {
@@ -13,7 +13,7 @@ pub enum Detail {
     NoData,
 }
 
-impl<'a> Display for Detail {
+impl Display for Detail {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
             Self::Full => write!(f, "full"),
@@ -23,3 +23,16 @@ impl<'a> Display for Detail {
         }
     }
 }
+
+impl Detail {
+    /// The SDMX 3.0 `attributes` and `measures` query values replacing `detail`, or `None`
+    /// when the defaults already return everything.
+    pub fn sdmx_3_0_attributes_and_measures(&self) -> Option<(&'static str, &'static str)> {
+        match self {
+            Self::Full => None,
+            Self::DataOnly => Some(("none", "all")),
+            Self::SeriesKeysOnly => Some(("none", "none")),
+            Self::NoData => Some(("dsd", "none")),
+        }
+    }
+}
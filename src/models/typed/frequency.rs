@@ -0,0 +1,31 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+#[derive(
+    Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, EnumIter,
+)]
+pub enum Frequency {
+    Annual,
+    SemiAnnual,
+    FourMonthly,
+    Quarterly,
+    Monthly,
+    Weekly,
+    Daily,
+}
+
+impl Display for Frequency {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Annual => write!(f, "A"),
+            Self::SemiAnnual => write!(f, "S"),
+            Self::FourMonthly => write!(f, "T"),
+            Self::Quarterly => write!(f, "Q"),
+            Self::Monthly => write!(f, "M"),
+            Self::Weekly => write!(f, "W"),
+            Self::Daily => write!(f, "D"),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+/// Why an observation has no value.
+#[derive(
+    Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, EnumIter,
+)]
+pub enum MissingValue {
+    /// `NaN`, `null`, `..` or an empty value.
+    NotAvailable,
+    /// `np`, the ABS marker for a value not published to protect confidentiality.
+    Confidential,
+}
+
+impl MissingValue {
+    /// The missing value marked by the lexical form of a value, if any.
+    pub fn from_lexical(str: &str) -> Option<Self> {
+        match str.trim() {
+            "" | "NaN" | "null" | ".." => Some(Self::NotAvailable),
+            "np" => Some(Self::Confidential),
+            _ => None,
+        }
+    }
+}
+
+impl Display for MissingValue {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::NotAvailable => write!(f, "NaN"),
+            Self::Confidential => write!(f, "np"),
+        }
+    }
+}
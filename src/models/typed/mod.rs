@@ -1,17 +1,30 @@
+pub mod data_format;
+pub mod data_stream;
 pub mod dataflow_identifier;
+pub mod dataflow_structure;
 pub mod datakey;
 pub mod datakey_dimension;
 pub mod detail;
 pub mod dimension_at_observation;
+pub mod frequency;
 pub mod meta_detail;
+pub mod missing_value;
 pub mod month;
 pub mod period;
+pub mod protocol_version;
 pub mod quarter;
+pub mod rate_limiter;
 pub mod reference;
+pub mod retry_policy;
 pub mod sdmx_client;
 pub mod sdmx_data_request;
+pub mod sdmx_json_version;
 pub mod sdmx_meta_request;
 pub mod sdmx_request;
 pub mod semester;
+pub mod structure_format;
+pub mod structure_registry;
 pub mod structure_type;
+pub mod time_series;
+pub mod trimester;
 pub mod version;
@@ -1,12 +1,28 @@
-use std::fmt;
+use std::{fmt, str::FromStr};
 
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
+use crate::{error_code::ErrorCode, result::Result};
+
+use super::period;
+
 #[derive(
-    Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, EnumIter,
+    Debug,
+    Copy,
+    Clone,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    EnumIter,
+    Default,
 )]
 pub enum Month {
+    #[default]
     Jan,
     Feb,
     Mar,
@@ -41,8 +57,18 @@ impl fmt::Display for Month {
     }
 }
 
-impl Default for Month {
-    fn default() -> Self {
-        Self::Jan
+impl TryFrom<&str> for Month {
+    type Error = ErrorCode;
+
+    fn try_from(str: &str) -> Result<Self> {
+        period::parse_variant(str)
+    }
+}
+
+impl FromStr for Month {
+    type Err = ErrorCode;
+
+    fn from_str(str: &str) -> Result<Self> {
+        Self::try_from(str)
     }
 }
@@ -1,9 +1,20 @@
-use std::fmt::{self, Display, Formatter};
+use std::{
+    fmt::{self, Display, Formatter},
+    mem,
+    ops::{Add, Sub},
+    str::FromStr,
+};
 
+use chrono::{Datelike, NaiveDate, TimeDelta, Weekday};
 use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
-use super::{month::Month, quarter::Quarter, semester::Semester};
+use crate::{error_code::ErrorCode, result::Result};
+
+use super::{
+    frequency::Frequency, month::Month, quarter::Quarter, semester::Semester, trimester::Trimester,
+};
 
 #[derive(
     Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, EnumIter,
@@ -13,6 +24,293 @@ pub enum Period {
     YearSemester(u16, Semester),
     YearQuarter(u16, Quarter),
     YearMonth(u16, Month),
+    /// An ISO 8601 week, from 1 to 52 or 53.
+    YearWeek(u16, u8),
+    YearMonthDay(u16, Month, u8),
+    /// The single reporting year period, `A1`.
+    ReportingYear(u16),
+    YearTrimester(u16, Trimester),
+    /// A reporting day, from 1 to 365 or 366.
+    ReportingDay(u16, u16),
+}
+
+impl Period {
+    pub fn parse(str: &str) -> Result<Self> {
+        Self::try_from(str)
+    }
+
+    pub fn frequency(&self) -> Frequency {
+        match self {
+            Self::Year(_) | Self::ReportingYear(_) => Frequency::Annual,
+            Self::YearSemester(..) => Frequency::SemiAnnual,
+            Self::YearTrimester(..) => Frequency::FourMonthly,
+            Self::YearQuarter(..) => Frequency::Quarterly,
+            Self::YearMonth(..) => Frequency::Monthly,
+            Self::YearWeek(..) => Frequency::Weekly,
+            Self::YearMonthDay(..) | Self::ReportingDay(..) => Frequency::Daily,
+        }
+    }
+
+    /// The period `n` periods of the same kind after this one, or `None` when the result
+    /// falls outside the years a `Period` can hold.
+    pub fn checked_add(self, n: i32) -> Option<Self> {
+        let n = i64::from(n);
+
+        let period = match self {
+            Self::Year(year) => Self::Year(shift(year, 0, 1, n)?.0),
+            Self::ReportingYear(year) => Self::ReportingYear(shift(year, 0, 1, n)?.0),
+            Self::YearSemester(year, semester) => {
+                let (year, index) = shift(year, index_of(semester), 2, n)?;
+                Self::YearSemester(year, nth(index)?)
+            }
+            Self::YearTrimester(year, trimester) => {
+                let (year, index) = shift(year, index_of(trimester), 3, n)?;
+                Self::YearTrimester(year, nth(index)?)
+            }
+            Self::YearQuarter(year, quarter) => {
+                let (year, index) = shift(year, index_of(quarter), 4, n)?;
+                Self::YearQuarter(year, nth(index)?)
+            }
+            Self::YearMonth(year, month) => {
+                let (year, index) = shift(year, index_of(month), 12, n)?;
+                Self::YearMonth(year, nth(index)?)
+            }
+            Self::YearWeek(..) => {
+                let date = self.first_date().ok()?;
+                week(date.checked_add_signed(TimeDelta::try_weeks(n)?)?)?
+            }
+            Self::YearMonthDay(..) => {
+                let date = self.first_date().ok()?;
+                day(date.checked_add_signed(TimeDelta::try_days(n)?)?)?
+            }
+            Self::ReportingDay(..) => {
+                let date = self.first_date().ok()?;
+                let date = date.checked_add_signed(TimeDelta::try_days(n)?)?;
+                Self::ReportingDay(u16::try_from(date.year()).ok()?, date.ordinal() as u16)
+            }
+        };
+
+        Some(period)
+    }
+
+    /// The following period, panicking past the last year a `Period` can hold.
+    pub fn next(self) -> Self {
+        self + 1
+    }
+
+    /// The preceding period, panicking before year zero.
+    pub fn prev(self) -> Self {
+        self - 1
+    }
+
+    /// Every period from `start` to `end` inclusive, in steps of `start`'s frequency. An
+    /// `end` of another frequency is converted to the period containing its first day.
+    pub fn range(start: Self, end: Self) -> Result<PeriodRange> {
+        let end = end.to_frequency(start.frequency())?;
+
+        if mem::discriminant(&start) != mem::discriminant(&end) {
+            return Err(ErrorCode::PeriodRangeMismatch(
+                start.to_string().into(),
+                end.to_string().into(),
+            ));
+        }
+
+        Ok(PeriodRange {
+            next: Some(start),
+            end,
+        })
+    }
+
+    pub fn first_date(&self) -> Result<NaiveDate> {
+        let date = match *self {
+            Self::Year(year) | Self::ReportingYear(year) => ymd(year, 1, 1),
+            Self::YearSemester(year, semester) => ymd(year, index_of(semester) * 6 + 1, 1),
+            Self::YearTrimester(year, trimester) => ymd(year, index_of(trimester) * 4 + 1, 1),
+            Self::YearQuarter(year, quarter) => ymd(year, index_of(quarter) * 3 + 1, 1),
+            Self::YearMonth(year, month) => ymd(year, index_of(month) + 1, 1),
+            Self::YearMonthDay(year, month, day) => ymd(year, index_of(month) + 1, day.into()),
+            Self::YearWeek(year, week) => {
+                NaiveDate::from_isoywd_opt(year.into(), week.into(), Weekday::Mon)
+            }
+            Self::ReportingDay(year, day) => NaiveDate::from_yo_opt(year.into(), day.into()),
+        };
+
+        date.ok_or_else(|| ErrorCode::InvalidPeriod(self.to_string().into()))
+    }
+
+    pub fn last_date(&self) -> Result<NaiveDate> {
+        self.checked_add(1)
+            .and_then(|next| next.first_date().ok()?.pred_opt())
+            .ok_or_else(|| ErrorCode::InvalidPeriod(self.to_string().into()))
+    }
+
+    /// The period of `frequency` containing `date`.
+    pub fn containing(date: NaiveDate, frequency: Frequency) -> Result<Self> {
+        let year = u16::try_from(date.year());
+        let index = i64::from(date.month0());
+
+        let period = year.ok().and_then(|year| {
+            let period = match frequency {
+                Frequency::Annual => Self::Year(year),
+                Frequency::SemiAnnual => Self::YearSemester(year, nth(index / 6)?),
+                Frequency::FourMonthly => Self::YearTrimester(year, nth(index / 4)?),
+                Frequency::Quarterly => Self::YearQuarter(year, nth(index / 3)?),
+                Frequency::Monthly => Self::YearMonth(year, nth(index)?),
+                Frequency::Weekly => week(date)?,
+                Frequency::Daily => day(date)?,
+            };
+
+            Some(period)
+        });
+
+        period.ok_or_else(|| ErrorCode::InvalidPeriod(date.to_string().into()))
+    }
+
+    /// The period of `frequency` containing this period's first day, such as the quarter
+    /// of a month. Periods already of `frequency` are returned unchanged.
+    pub fn to_frequency(&self, frequency: Frequency) -> Result<Self> {
+        if self.frequency() == frequency {
+            return Ok(*self);
+        }
+
+        Self::containing(self.first_date()?, frequency)
+    }
+}
+
+impl Add<i32> for Period {
+    type Output = Self;
+
+    fn add(self, n: i32) -> Self {
+        self.checked_add(n).expect("period out of range")
+    }
+}
+
+impl Sub<i32> for Period {
+    type Output = Self;
+
+    fn sub(self, n: i32) -> Self {
+        self.checked_add(n.checked_neg().expect("period out of range"))
+            .expect("period out of range")
+    }
+}
+
+/// Iterates the periods of a [`Period::range`].
+#[derive(Debug, Clone)]
+pub struct PeriodRange {
+    next: Option<Period>,
+    end: Period,
+}
+
+impl Iterator for PeriodRange {
+    type Item = Period;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.filter(|current| *current <= self.end)?;
+        self.next = current.checked_add(1);
+        Some(current)
+    }
+}
+
+fn index_of<T: IntoEnumIterator + PartialEq>(value: T) -> i64 {
+    T::iter().position(|item| item == value).unwrap_or_default() as i64
+}
+
+fn nth<T: IntoEnumIterator>(index: i64) -> Option<T> {
+    T::iter().nth(usize::try_from(index).ok()?)
+}
+
+/// The variant of `T` that displays as `str`, such as `Quarter::Fourth` for `Q4`.
+pub(crate) fn parse_variant<T: IntoEnumIterator + Display>(str: &str) -> Result<T> {
+    T::iter()
+        .find(|variant| variant.to_string() == str)
+        .ok_or_else(|| ErrorCode::PeriodParse(str.into()))
+}
+
+/// Moves the `index`th of `per_year` periods in `year` by `n` periods.
+fn shift(year: u16, index: i64, per_year: i64, n: i64) -> Option<(u16, i64)> {
+    let ordinal = i64::from(year) * per_year + index + n;
+    let year = u16::try_from(ordinal.div_euclid(per_year)).ok()?;
+
+    Some((year, ordinal.rem_euclid(per_year)))
+}
+
+fn ymd(year: u16, month: i64, day: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(year.into(), u32::try_from(month).ok()?, day)
+}
+
+fn week(date: NaiveDate) -> Option<Period> {
+    let week = date.iso_week();
+    Some(Period::YearWeek(
+        u16::try_from(week.year()).ok()?,
+        week.week() as u8,
+    ))
+}
+
+fn day(date: NaiveDate) -> Option<Period> {
+    Some(Period::YearMonthDay(
+        u16::try_from(date.year()).ok()?,
+        nth(date.month0().into())?,
+        date.day() as u8,
+    ))
+}
+
+/// Parses a number with exactly `digits` ASCII digits.
+fn number<T: FromStr>(str: &str, digits: usize) -> Option<T> {
+    if str.len() != digits || !str.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    str.parse().ok()
+}
+
+fn parse_after_year(year: u16, rest: &str) -> Option<Period> {
+    let (prefix, number_str) = rest.split_at(rest.find(|c: char| c.is_ascii_digit())?);
+
+    let period = match prefix {
+        "" if rest.len() == 2 => Period::YearMonth(year, rest.parse().ok()?),
+        "" => {
+            let (month, day) = rest.split_once('-')?;
+            let month = month.parse().ok()?;
+            Period::YearMonthDay(year, month, number(day, 2)?)
+        }
+        "S" => Period::YearSemester(year, rest.parse().ok()?),
+        "Q" => Period::YearQuarter(year, rest.parse().ok()?),
+        "T" => Period::YearTrimester(year, rest.parse().ok()?),
+        "M" => Period::YearMonth(year, number_str.parse().ok()?),
+        "A" if number_str == "1" => Period::ReportingYear(year),
+        "W" => Period::YearWeek(year, number(number_str, 2)?),
+        "D" => Period::ReportingDay(year, number(number_str, 3)?),
+        _ => return None,
+    };
+
+    // Rejects days and weeks the year does not have.
+    period.first_date().is_ok().then_some(period)
+}
+
+impl TryFrom<&str> for Period {
+    type Error = ErrorCode;
+
+    fn try_from(str: &str) -> Result<Self> {
+        let (year, rest) = str.split_once('-').unwrap_or((str, ""));
+
+        let period = number(year, 4).and_then(|year| {
+            if rest.is_empty() && str.len() == 4 {
+                Some(Self::Year(year))
+            } else {
+                parse_after_year(year, rest)
+            }
+        });
+
+        period.ok_or_else(|| ErrorCode::PeriodParse(str.into()))
+    }
+}
+
+impl FromStr for Period {
+    type Err = ErrorCode;
+
+    fn from_str(str: &str) -> Result<Self> {
+        Self::try_from(str)
+    }
 }
 
 impl Display for Period {
@@ -22,6 +320,11 @@ impl Display for Period {
             Self::YearSemester(year, semester) => write!(f, "{:04}-{}", year, semester),
             Self::YearQuarter(year, quarter) => write!(f, "{:04}-{}", year, quarter),
             Self::YearMonth(year, month) => write!(f, "{:04}-{}", year, month),
+            Self::YearWeek(year, week) => write!(f, "{:04}-W{:02}", year, week),
+            Self::YearMonthDay(year, month, day) => write!(f, "{:04}-{}-{:02}", year, month, day),
+            Self::ReportingYear(year) => write!(f, "{:04}-A1", year),
+            Self::YearTrimester(year, trimester) => write!(f, "{:04}-{}", year, trimester),
+            Self::ReportingDay(year, day) => write!(f, "{:04}-D{:03}", year, day),
         }
     }
 }
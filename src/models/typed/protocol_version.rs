@@ -0,0 +1,35 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+/// The SDMX REST protocol used to build request urls. `Sdmx21` targets the original beta
+/// endpoints, `Sdmx30` the SDMX 3.0 endpoints.
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    EnumIter,
+)]
+pub enum ProtocolVersion {
+    #[default]
+    Sdmx21,
+    Sdmx30,
+}
+
+impl Display for ProtocolVersion {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Sdmx21 => write!(f, "2.1"),
+            Self::Sdmx30 => write!(f, "3.0"),
+        }
+    }
+}
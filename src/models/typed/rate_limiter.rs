@@ -0,0 +1,157 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use tokio::{
+    sync::{Mutex, OwnedSemaphorePermit, Semaphore},
+    time::Instant,
+};
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+/// A token bucket limiting how often, and how many concurrent, requests an `SdmxClient`
+/// sends. Shared by every request made through the client. Built with a
+/// `RateLimiterBuilder`.
+#[derive(Debug)]
+pub struct RateLimiter {
+    requests_per_second: Option<f64>,
+    burst: u32,
+    bucket: Mutex<Bucket>,
+    in_flight: Option<Arc<Semaphore>>,
+    requests: AtomicU64,
+    total_wait_nanos: AtomicU64,
+    max_wait_nanos: AtomicU64,
+}
+
+/// A snapshot of how long callers have waited on a `RateLimiter`.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimiterMetrics {
+    pub requests: u64,
+    pub total_wait: Duration,
+    pub max_wait: Duration,
+}
+
+/// Held while a request is in flight, until its body has been read in full; releases
+/// its in-flight slot when dropped.
+#[derive(Debug)]
+pub struct RateLimitPermit {
+    _in_flight: Option<OwnedSemaphorePermit>,
+    waited: Duration,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(
+        requests_per_second: Option<f64>,
+        burst: u32,
+        max_in_flight: Option<usize>,
+    ) -> Self {
+        let burst = burst.max(1);
+
+        Self {
+            requests_per_second,
+            burst,
+            bucket: Mutex::new(Bucket {
+                tokens: burst as f64,
+                refilled_at: Instant::now(),
+            }),
+            in_flight: max_in_flight.map(|max| Arc::new(Semaphore::new(max.max(1)))),
+            requests: AtomicU64::new(0),
+            total_wait_nanos: AtomicU64::new(0),
+            max_wait_nanos: AtomicU64::new(0),
+        }
+    }
+
+    pub fn requests_per_second(&self) -> Option<f64> {
+        self.requests_per_second
+    }
+
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+
+    /// Waits until a request may be sent under both the rate and in-flight limits.
+    pub async fn acquire(&self) -> RateLimitPermit {
+        let started = Instant::now();
+
+        let in_flight = match &self.in_flight {
+            Some(semaphore) => Some(
+                semaphore
+                    .clone()
+                    .acquire_owned()
+                    .await
+                    .expect("rate limiter semaphore is never closed"),
+            ),
+            None => None,
+        };
+
+        if let Some(delay) = self.reserve_token().await {
+            tokio::time::sleep(delay).await;
+        }
+
+        let waited = started.elapsed();
+        self.record(waited);
+
+        RateLimitPermit {
+            _in_flight: in_flight,
+            waited,
+        }
+    }
+
+    /// Takes a token from the bucket, going into debt when empty, and returns how long
+    /// the caller must wait for that token to have been refilled.
+    async fn reserve_token(&self) -> Option<Duration> {
+        let rate = self.requests_per_second?;
+        let mut bucket = self.bucket.lock().await;
+
+        let now = Instant::now();
+        let refilled = now.duration_since(bucket.refilled_at).as_secs_f64() * rate;
+        bucket.tokens = (bucket.tokens + refilled).min(self.burst as f64);
+        bucket.refilled_at = now;
+        bucket.tokens -= 1.0;
+
+        if bucket.tokens >= 0.0 {
+            None
+        } else {
+            Some(Duration::from_secs_f64(-bucket.tokens / rate))
+        }
+    }
+
+    fn record(&self, waited: Duration) {
+        let nanos = u64::try_from(waited.as_nanos()).unwrap_or(u64::MAX);
+
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        self.total_wait_nanos.fetch_add(nanos, Ordering::Relaxed);
+        self.max_wait_nanos.fetch_max(nanos, Ordering::Relaxed);
+    }
+
+    pub fn metrics(&self) -> RateLimiterMetrics {
+        RateLimiterMetrics {
+            requests: self.requests.load(Ordering::Relaxed),
+            total_wait: Duration::from_nanos(self.total_wait_nanos.load(Ordering::Relaxed)),
+            max_wait: Duration::from_nanos(self.max_wait_nanos.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+impl RateLimiterMetrics {
+    pub fn average_wait(&self) -> Duration {
+        match self.requests {
+            0 => Duration::ZERO,
+            requests => self.total_wait / requests.min(u32::MAX as u64) as u32,
+        }
+    }
+}
+
+impl RateLimitPermit {
+    pub fn waited(&self) -> Duration {
+        self.waited
+    }
+}
@@ -18,7 +18,7 @@ pub enum Reference {
     StructureType(StructureType),
 }
 
-impl<'a> Display for Reference {
+impl Display for Reference {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
             Self::None => write!(f, "none"),
@@ -31,3 +31,12 @@ impl<'a> Display for Reference {
         }
     }
 }
+
+impl Reference {
+    pub fn sdmx_3_0_name(&self) -> String {
+        match self {
+            Self::StructureType(structure_type) => structure_type.sdmx_3_0_name().into(),
+            _ => self.to_string(),
+        }
+    }
+}
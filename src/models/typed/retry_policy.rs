@@ -0,0 +1,164 @@
+use std::{
+    borrow::Borrow,
+    time::{Duration, SystemTime},
+};
+
+use rand::Rng;
+use reqwest::StatusCode;
+
+use crate::{config::Config, error_code::ErrorCode, result::Result, transport::TransportResponse};
+
+/// How an `SdmxClient` retries requests that fail with a transient status or a
+/// connection error. Built with a `RetryPolicyBuilder`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+    jitter: bool,
+    retryable_statuses: Box<[StatusCode]>,
+    respect_retry_after: bool,
+    max_retry_after: Duration,
+}
+
+impl RetryPolicy {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        max_attempts: u32,
+        initial_backoff: Duration,
+        max_backoff: Duration,
+        multiplier: f64,
+        jitter: bool,
+        retryable_statuses: Box<[StatusCode]>,
+        respect_retry_after: bool,
+        max_retry_after: Duration,
+    ) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            initial_backoff,
+            max_backoff,
+            multiplier,
+            jitter,
+            retryable_statuses,
+            respect_retry_after,
+            max_retry_after,
+        }
+    }
+
+    /// A policy that sends every request exactly once.
+    pub fn none() -> Self {
+        Self::new(
+            1,
+            Duration::ZERO,
+            Duration::ZERO,
+            1.0,
+            false,
+            Box::new([]),
+            false,
+            Duration::ZERO,
+        )
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    pub fn initial_backoff(&self) -> Duration {
+        self.initial_backoff
+    }
+
+    pub fn max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+
+    pub fn jitter(&self) -> bool {
+        self.jitter
+    }
+
+    pub fn retryable_statuses(&self) -> &[StatusCode] {
+        &self.retryable_statuses
+    }
+
+    pub fn respect_retry_after(&self) -> bool {
+        self.respect_retry_after
+    }
+
+    pub fn max_retry_after(&self) -> Duration {
+        self.max_retry_after
+    }
+
+    pub fn is_retryable(&self, status: StatusCode) -> bool {
+        self.retryable_statuses.contains(&status)
+    }
+
+    /// The exponential backoff before retry number `retry` (starting at one), without jitter.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1) as i32;
+        let backoff = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+
+        Duration::try_from_secs_f64(backoff)
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff)
+    }
+
+    pub(crate) fn should_retry<T>(&self, result: &Result<T>) -> bool
+    where
+        T: Borrow<TransportResponse>,
+    {
+        match result {
+            Ok(response) => self.is_retryable(response.borrow().status),
+            Err(ErrorCode::Reqwest(e)) => e.is_timeout() || e.is_connect(),
+            Err(_) => false,
+        }
+    }
+
+    /// The delay before retry number `retry`, taken from the `Retry-After` header of the
+    /// failed response when present and respected, up to `max_retry_after`, otherwise
+    /// from the jittered backoff.
+    pub(crate) fn delay(&self, retry: u32, response: Option<&TransportResponse>) -> Duration {
+        let retry_after = response
+            .filter(|_| self.respect_retry_after)
+            .and_then(|r| r.header(Config::HEADER_RETRY_AFTER_KEY))
+            .and_then(parse_retry_after);
+
+        if let Some(retry_after) = retry_after {
+            return retry_after.min(self.max_retry_after);
+        }
+
+        let backoff = self.backoff(retry);
+
+        if self.jitter && !backoff.is_zero() {
+            let half = backoff / 2;
+            half + rand::thread_rng().gen_range(Duration::ZERO..=half)
+        } else {
+            backoff
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+/// Parses a `Retry-After` header given either as delay seconds or as an HTTP date.
+pub fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
@@ -1,20 +1,101 @@
-use std::{cell::OnceCell, sync::OnceLock};
+use std::sync::{Arc, OnceLock};
 
-static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+use crate::{
+    builders::{
+        datakey_builder::DataKeyBuilder, sdmx_client_builder::SdmxClientBuilder,
+        sdmx_data_request_builder::SdmxDataRequestBuilder,
+        sdmx_meta_request_builder::SdmxMetaRequestBuilder,
+    },
+    transport::{http_cache::HttpCache, Transport},
+};
 
+use super::{
+    dataflow_identifier::DataflowIdentifier, protocol_version::ProtocolVersion,
+    rate_limiter::RateLimiter, retry_policy::RetryPolicy, structure_type::StructureType,
+};
+
+static DEFAULT_CLIENT: OnceLock<SdmxClient> = OnceLock::new();
+
+/// A configured connection to an SDMX API. Owns its base url, default headers,
+/// optional api key and the `Transport` requests are sent through, so several
+/// clients can target different hosts within the same process.
+#[derive(Debug, Clone)]
 pub struct SdmxClient {
-    inner: OnceCell<&'static reqwest::Client>,
+    pub(crate) base_url: Box<str>,
+    pub(crate) headers: Box<[(Box<str>, Box<str>)]>,
+    pub(crate) key: Option<Box<str>>,
+    pub(crate) transport: Arc<dyn Transport>,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) rate_limiter: Option<Arc<RateLimiter>>,
+    pub(crate) cache: Option<HttpCache>,
+    pub(crate) protocol_version: ProtocolVersion,
 }
 
 impl SdmxClient {
-    pub fn get_or_init() -> Self {
-        let inner = CLIENT.get_or_init(reqwest::Client::new);
-        Self {
-            inner: inner.into(),
-        }
+    /// The process wide client used by builders that are not given one, pointed at
+    /// `Config::BASE_URL`.
+    pub fn get_or_init() -> &'static Self {
+        DEFAULT_CLIENT.get_or_init(|| {
+            SdmxClientBuilder::new()
+                .build()
+                .expect("default client should always build")
+        })
+    }
+
+    pub fn builder() -> SdmxClientBuilder {
+        SdmxClientBuilder::new()
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn headers(&self) -> &[(Box<str>, Box<str>)] {
+        &self.headers
+    }
+
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+
+    pub fn transport(&self) -> &dyn Transport {
+        self.transport.as_ref()
+    }
+
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_deref()
+    }
+
+    pub fn cache(&self) -> Option<&HttpCache> {
+        self.cache.as_ref()
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    pub fn data_request<'a>(
+        &'a self,
+        dataflow_identifier: &'a DataflowIdentifier,
+    ) -> SdmxDataRequestBuilder<'a> {
+        SdmxDataRequestBuilder::new(dataflow_identifier).client(self)
+    }
+
+    pub fn meta_request<'a>(
+        &'a self,
+        structure_type: &'a StructureType,
+    ) -> SdmxMetaRequestBuilder<'a> {
+        SdmxMetaRequestBuilder::new(structure_type).client(self)
     }
 
-    pub fn inner(&self) -> &reqwest::Client {
-        self.inner.get().expect("client not initialized")
+    pub fn datakey<'a>(
+        &'a self,
+        dataflow_identifier: &'a DataflowIdentifier,
+    ) -> DataKeyBuilder<'a> {
+        DataKeyBuilder::new(dataflow_identifier).client(self)
     }
 }
@@ -1,27 +1,129 @@
-use super::sdmx_request::SdmxRequest;
-use crate::models::derived::{data_sets::DataSets, sdmx_response::SdmxResponse};
-use crate::result::Result;
+use super::{
+    data_format::DataFormat,
+    data_stream::{self, DataStream},
+    dataflow_identifier::DataflowIdentifier,
+    dataflow_structure::DataflowStructure,
+    sdmx_client::SdmxClient,
+    sdmx_request::SdmxRequest,
+};
+use crate::models::derived::{data_sets::DataSets, sdmx_csv::SdmxCsv, sdmx_response::SdmxResponse};
+use crate::{
+    error_code::ErrorCode,
+    parsers::{sdmx_csv, sdmx_json},
+    result::Result,
+};
+
+#[cfg(feature = "xml")]
+use crate::parsers::sdmx_ml;
 
 pub struct SdmxDataRequest<'a> {
     request: SdmxRequest<'a>,
+    format: DataFormat,
+    dataflow_identifier: &'a DataflowIdentifier,
+    structure: Option<&'a DataflowStructure>,
 }
 
 impl<'a> SdmxDataRequest<'a> {
+    pub(crate) fn new(
+        request: SdmxRequest<'a>,
+        format: DataFormat,
+        dataflow_identifier: &'a DataflowIdentifier,
+        structure: Option<&'a DataflowStructure>,
+    ) -> Self {
+        Self {
+            request,
+            format,
+            dataflow_identifier,
+            structure,
+        }
+    }
+
     pub fn url(&self) -> &str {
         self.request.url()
     }
 
+    pub fn client(&self) -> &'a SdmxClient {
+        self.request.client()
+    }
+
     pub fn headers(&self) -> &'a [(&'a str, &'a str)] {
         self.request.headers()
     }
 
+    pub fn format(&self) -> DataFormat {
+        self.format
+    }
+
+    pub fn dataflow_identifier(&self) -> &DataflowIdentifier {
+        self.dataflow_identifier
+    }
+
+    pub fn structure(&self) -> Option<&DataflowStructure> {
+        self.structure
+    }
+
+    async fn body(&self) -> Result<Box<[u8]>> {
+        let body_bytes = self.request.body().await?;
+
+        if body_bytes.is_empty() {
+            return Err(ErrorCode::HttpEmptyResponse);
+        }
+
+        Ok(body_bytes)
+    }
+
+    /// Sends the request and parses the response in the requested format. SDMX-JSON
+    /// responses may be either 1.0 or 2.0 data messages.
     pub async fn send(&self) -> Result<SdmxResponse<DataSets>> {
-        self.request.send::<DataSets>().await
+        let body_bytes = self.body().await?;
+
+        match self.format {
+            DataFormat::SdmxJson => sdmx_json::parse_data(&body_bytes),
+            DataFormat::SdmxCsv => sdmx_csv::parse_data(&body_bytes),
+            #[cfg(feature = "xml")]
+            DataFormat::StructureSpecificXml => {
+                let fetched;
+                let structure = match self.structure {
+                    Some(structure) => structure,
+                    None => {
+                        fetched = DataflowStructure::fetch(
+                            self.request.client(),
+                            self.dataflow_identifier,
+                        )
+                        .await?;
+                        &fetched
+                    }
+                };
+                sdmx_ml::parse_data(&body_bytes, Some(structure.dimensions()))
+            }
+            #[cfg(feature = "xml")]
+            DataFormat::GenericXml => sdmx_ml::parse_data(&body_bytes, None),
+        }
     }
-}
 
-impl<'a> From<SdmxRequest<'a>> for SdmxDataRequest<'a> {
-    fn from(request: SdmxRequest<'a>) -> Self {
-        SdmxDataRequest { request }
+    /// Sends an SDMX-CSV request and returns its typed rows, without grouping them into
+    /// series.
+    pub async fn send_rows(&self) -> Result<SdmxCsv> {
+        if self.format != DataFormat::SdmxCsv {
+            return Err(ErrorCode::UnsupportedDataMessage(
+                format!("rows are only read from sdmx-csv, not {}", self.format).into(),
+            ));
+        }
+
+        sdmx_csv::parse_rows(&self.body().await?)
+    }
+
+    /// Sends an SDMX-JSON or SDMX-CSV request and reads its series as the response body
+    /// arrives, rather than holding the whole message in memory. SDMX-JSON 1.0 messages
+    /// are held until their structure is read, see `sdmx_json::read_series`.
+    pub async fn send_stream(&self) -> Result<DataStream> {
+        if !matches!(self.format, DataFormat::SdmxJson | DataFormat::SdmxCsv) {
+            return Err(data_stream::unsupported(self.format));
+        }
+
+        Ok(DataStream::spawn(
+            self.request.body_stream().await?,
+            self.format,
+        ))
     }
 }
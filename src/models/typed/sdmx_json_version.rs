@@ -0,0 +1,50 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+/// The SDMX-JSON message format version, detected from the `meta.schema` url.
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    EnumIter,
+)]
+pub enum SdmxJsonVersion {
+    #[default]
+    V1,
+    V2,
+}
+
+impl SdmxJsonVersion {
+    /// Reads the version segment before the schema file name, such as `2.0.0` in
+    /// `.../schemas/2.0.0/sdmx-json-data-schema.json`.
+    pub fn from_schema(schema: &str) -> Self {
+        let version = schema.rsplit('/').nth(1).unwrap_or_default();
+        let numeric = version
+            .split('.')
+            .all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()));
+
+        match version.split('.').next() {
+            Some("2") if numeric => Self::V2,
+            _ => Self::V1,
+        }
+    }
+}
+
+impl Display for SdmxJsonVersion {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::V1 => write!(f, "1.0.0"),
+            Self::V2 => write!(f, "2.0.0"),
+        }
+    }
+}
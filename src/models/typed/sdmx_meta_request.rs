@@ -1,13 +1,18 @@
-use super::sdmx_request::SdmxRequest;
+use super::{sdmx_request::SdmxRequest, structure_format::StructureFormat};
 use crate::models::derived::meta_data_map::MetaDataMap;
 use crate::models::derived::sdmx_response::SdmxResponse;
 use crate::result::Result;
 
 pub struct SdmxMetaRequest<'a> {
     request: SdmxRequest<'a>,
+    format: StructureFormat,
 }
 
 impl<'a> SdmxMetaRequest<'a> {
+    pub(crate) fn new(request: SdmxRequest<'a>, format: StructureFormat) -> Self {
+        Self { request, format }
+    }
+
     pub fn url(&self) -> &str {
         self.request.url()
     }
@@ -16,13 +21,29 @@ impl<'a> SdmxMetaRequest<'a> {
         self.request.headers()
     }
 
+    pub fn format(&self) -> StructureFormat {
+        self.format
+    }
+
     pub async fn send(&self) -> Result<SdmxResponse<MetaDataMap>> {
-        self.request.send::<MetaDataMap>().await
+        match self.format {
+            StructureFormat::SdmxJson => self.request.send::<MetaDataMap>().await,
+            #[cfg(feature = "xml")]
+            StructureFormat::SdmxMl => {
+                let body_bytes = self.request.body().await?;
+
+                if body_bytes.is_empty() {
+                    return Err(crate::error_code::ErrorCode::HttpEmptyResponse);
+                }
+
+                crate::parsers::sdmx_ml::parse_structure(&body_bytes)
+            }
+        }
     }
 }
 
 impl<'a> From<SdmxRequest<'a>> for SdmxMetaRequest<'a> {
     fn from(request: SdmxRequest<'a>) -> Self {
-        SdmxMetaRequest { request }
+        Self::new(request, StructureFormat::default())
     }
 }
@@ -1,29 +1,66 @@
+use std::{borrow::Borrow, future::Future};
+
+use async_trait::async_trait;
+use reqwest::StatusCode;
 use url::Url;
 
 use crate::{
-    config::Config, error_code::ErrorCode, models::derived::sdmx_response::SdmxResponse,
+    config::Config,
+    error_code::ErrorCode,
+    models::derived::sdmx_response::SdmxResponse,
+    parsers::sdmx_json,
     result::Result,
+    transport::{BodyStream, BufferedBody, StreamingResponse, TransportResponse},
 };
 
-use super::sdmx_client::SdmxClient;
+use super::{rate_limiter::RateLimitPermit, sdmx_client::SdmxClient};
+
+/// A streamed body holding the rate limiter permit of its request, so the request stays
+/// in flight until the body has been read in full or dropped.
+struct PermittedBody {
+    body: Box<dyn BodyStream>,
+    permit: Option<RateLimitPermit>,
+}
+
+#[async_trait]
+impl BodyStream for PermittedBody {
+    async fn chunk(&mut self) -> Result<Option<Box<[u8]>>> {
+        let chunk = self.body.chunk().await;
+
+        if !matches!(chunk, Ok(Some(_))) {
+            self.permit = None;
+        }
+
+        chunk
+    }
+}
 
 pub struct SdmxRequest<'a> {
-    client: SdmxClient,
+    client: &'a SdmxClient,
     url: Url,
     key: Option<&'a str>,
     headers: &'a [(&'a str, &'a str)],
 }
 
 impl<'a> SdmxRequest<'a> {
-    pub fn new(url: Url, key: Option<&'a str>, headers: &'a [(&'a str, &'a str)]) -> Self {
+    pub fn new(
+        client: &'a SdmxClient,
+        url: Url,
+        key: Option<&'a str>,
+        headers: &'a [(&'a str, &'a str)],
+    ) -> Self {
         Self {
-            client: SdmxClient::get_or_init(),
+            client,
             url,
             key,
             headers,
         }
     }
 
+    pub fn client(&self) -> &'a SdmxClient {
+        self.client
+    }
+
     pub fn url(&self) -> &str {
         self.url.as_ref()
     }
@@ -32,46 +69,155 @@ impl<'a> SdmxRequest<'a> {
         self.headers
     }
 
-    pub async fn send<T>(&self) -> Result<SdmxResponse<T>>
+    fn request_headers(&self) -> Vec<(&str, &str)> {
+        let mut headers = self
+            .client
+            .headers()
+            .iter()
+            .map(|(key, value)| (key.as_ref(), value.as_ref()))
+            .chain(self.headers.iter().copied())
+            .collect::<Vec<_>>();
+
+        if let Some(key) = self.key.or(self.client.key()) {
+            headers.push((Config::HEADER_API_KEY, key));
+        }
+
+        headers
+    }
+
+    /// Sends the request through the client's transport, honouring its rate limiter and
+    /// retry policy.
+    async fn fetch(&self, headers: &[(&str, &str)]) -> Result<TransportResponse> {
+        let (response, _permit) = self
+            .retry(|| self.client.transport().get(self.url.as_ref(), headers))
+            .await?;
+
+        Ok(response)
+    }
+
+    /// Like `fetch`, but leaves the body to be read as it arrives. The request counts
+    /// against the rate limiter until its body has been read.
+    async fn fetch_stream(&self, headers: &[(&str, &str)]) -> Result<StreamingResponse> {
+        let (response, permit) = self
+            .retry(|| {
+                self.client
+                    .transport()
+                    .get_stream(self.url.as_ref(), headers)
+            })
+            .await?;
+
+        Ok(StreamingResponse {
+            head: response.head,
+            body: Box::new(PermittedBody {
+                body: response.body,
+                permit,
+            }),
+        })
+    }
+
+    /// Calls `send` under the rate limiter until it succeeds or the retry policy gives up,
+    /// returning the response with the permit it was sent under.
+    async fn retry<T, F, Fut>(&self, send: F) -> Result<(T, Option<RateLimitPermit>)>
     where
-        T: serde::de::DeserializeOwned,
+        T: Borrow<TransportResponse>,
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T>>,
     {
-        let mut request = self.client.inner().get(self.url.as_ref());
+        let retry_policy = self.client.retry_policy();
+        let mut attempt = 1;
+
+        loop {
+            let permit = match self.client.rate_limiter() {
+                Some(rate_limiter) => Some(rate_limiter.acquire().await),
+                None => None,
+            };
+
+            let result = send().await;
+
+            if attempt >= retry_policy.max_attempts() || !retry_policy.should_retry(&result) {
+                return result.map(|response| (response, permit));
+            }
+
+            drop(permit);
 
-        for header in self.headers {
-            request = request.header(header.0, header.1)
+            let response = result.as_ref().ok().map(Borrow::borrow);
+            tokio::time::sleep(retry_policy.delay(attempt, response)).await;
+            attempt += 1;
+        }
+    }
+
+    /// Returns the successful response body, served from or stored in the client's cache
+    /// when one is configured.
+    pub(crate) async fn body(&self) -> Result<Box<[u8]>> {
+        let mut headers = self.request_headers();
+
+        let accept = headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(Config::HEADER_ACCEPT_KEY))
+            .map(|(_, value)| Box::<str>::from(*value));
+        let accept = accept.as_deref();
+
+        let Some(cache) = self.client.cache() else {
+            let response = self.fetch(&headers).await?;
+
+            if !response.status.is_success() {
+                return Err(ErrorCode::Http(response.status));
+            }
+
+            return Ok(response.body);
+        };
+
+        let cached = cache.get(self.url(), accept)?;
+
+        if let Some(entry) = &cached {
+            if cache.is_fresh(entry) {
+                return Ok(entry.body.as_bytes().into());
+            }
+            headers.extend(entry.conditional_headers());
+        }
+
+        let response = self.fetch(&headers).await?;
+
+        if let (StatusCode::NOT_MODIFIED, Some(mut entry)) = (response.status, cached) {
+            cache.touch(&mut entry)?;
+            return Ok(entry.body.as_bytes().into());
         }
 
-        if let Some(key) = &self.key {
-            request = request.header(Config::HEADER_API_KEY, *key);
+        if !response.status.is_success() {
+            return Err(ErrorCode::Http(response.status));
         }
 
-        let response = request.send().await?;
+        cache.store(self.url(), accept, &response)?;
 
-        let status = response.status();
+        Ok(response.body)
+    }
 
-        if !status.is_success() {
-            return Err(ErrorCode::Http(status));
+    /// Returns the successful response body to be read as it arrives. Clients with a
+    /// cache read the whole body, so that it can be stored.
+    pub(crate) async fn body_stream(&self) -> Result<Box<dyn BodyStream>> {
+        if self.client.cache().is_some() {
+            return Ok(Box::new(BufferedBody::new(self.body().await?)));
         }
 
-        let body_bytes = response.bytes().await?;
+        let response = self.fetch_stream(&self.request_headers()).await?;
+
+        if !response.head.status.is_success() {
+            return Err(ErrorCode::Http(response.head.status));
+        }
+
+        Ok(response.body)
+    }
+
+    pub async fn send<T>(&self) -> Result<SdmxResponse<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let body_bytes = self.body().await?;
 
         if body_bytes.is_empty() {
             return Err(ErrorCode::HttpEmptyResponse);
         }
 
-        match serde_json::from_slice(&body_bytes) {
-            Ok(data) => Ok(data),
-            Err(e) => {
-                // Get a snippet of the text around the error location
-                let error_position = e.column();
-                let start = error_position.saturating_sub(10);
-                let end = (error_position + 10).min(body_bytes.len());
-                let snippet = &std::str::from_utf8(&body_bytes).expect("invalid utf8")[start..end];
-
-                // Return the enhanced error
-                Err(ErrorCode::JsonSliceDeserialization(e, snippet.into()))
-            }
-        }
+        sdmx_json::deserialize(&body_bytes)
     }
 }
@@ -0,0 +1,49 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+use crate::config::Config;
+
+/// The message format requested for structures. SDMX-ML requires the `xml` feature and
+/// is always requested as SDMX-ML 2.1.
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    EnumIter,
+)]
+pub enum StructureFormat {
+    #[default]
+    SdmxJson,
+    #[cfg(feature = "xml")]
+    SdmxMl,
+}
+
+impl StructureFormat {
+    pub fn accept_headers(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::SdmxJson => &[Config::ACCEPT_STRUCTURE_JSON],
+            #[cfg(feature = "xml")]
+            Self::SdmxMl => &[Config::ACCEPT_STRUCTURE_XML],
+        }
+    }
+}
+
+impl Display for StructureFormat {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::SdmxJson => write!(f, "sdmx-json"),
+            #[cfg(feature = "xml")]
+            Self::SdmxMl => write!(f, "sdmx-ml"),
+        }
+    }
+}
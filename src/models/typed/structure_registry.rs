@@ -0,0 +1,104 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use crate::result::Result;
+
+use super::{
+    dataflow_identifier::DataflowIdentifier, dataflow_structure::DataflowStructure,
+    sdmx_client::SdmxClient,
+};
+
+/// A client's base url and a dataflow identifier.
+type RegistryKey = (Box<str>, DataflowIdentifier);
+
+/// A thread safe, cheaply cloned memo of dataflow structures keyed by the client's base
+/// url and the dataflow identifier, so repeated `DataKeyBuilder` builds skip the
+/// metadata request.
+#[derive(Default, Debug, Clone)]
+pub struct StructureRegistry {
+    inner: Arc<RwLock<HashMap<RegistryKey, Arc<DataflowStructure>>>>,
+}
+
+impl StructureRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn key(client: &SdmxClient, id: &DataflowIdentifier) -> RegistryKey {
+        (client.base_url().into(), id.clone())
+    }
+
+    pub fn get(
+        &self,
+        client: &SdmxClient,
+        id: &DataflowIdentifier,
+    ) -> Option<Arc<DataflowStructure>> {
+        self.inner
+            .read()
+            .expect("structure registry lock poisoned")
+            .get(&Self::key(client, id))
+            .cloned()
+    }
+
+    pub fn insert(
+        &self,
+        client: &SdmxClient,
+        id: &DataflowIdentifier,
+        structure: DataflowStructure,
+    ) -> Arc<DataflowStructure> {
+        let structure = Arc::new(structure);
+
+        self.inner
+            .write()
+            .expect("structure registry lock poisoned")
+            .insert(Self::key(client, id), structure.clone());
+
+        structure
+    }
+
+    /// Returns the registered structure for `id`, fetching and registering it first if needed.
+    pub async fn get_or_fetch(
+        &self,
+        client: &SdmxClient,
+        id: &DataflowIdentifier,
+    ) -> Result<Arc<DataflowStructure>> {
+        if let Some(structure) = self.get(client, id) {
+            return Ok(structure);
+        }
+
+        let structure = DataflowStructure::fetch(client, id).await?;
+
+        Ok(self.insert(client, id, structure))
+    }
+
+    pub fn remove(
+        &self,
+        client: &SdmxClient,
+        id: &DataflowIdentifier,
+    ) -> Option<Arc<DataflowStructure>> {
+        self.inner
+            .write()
+            .expect("structure registry lock poisoned")
+            .remove(&Self::key(client, id))
+    }
+
+    pub fn clear(&self) {
+        self.inner
+            .write()
+            .expect("structure registry lock poisoned")
+            .clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner
+            .read()
+            .expect("structure registry lock poisoned")
+            .len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
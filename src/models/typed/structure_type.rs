@@ -3,7 +3,18 @@ use std::fmt::{self, Display, Formatter};
 use strum_macros::EnumIter;
 
 #[derive(
-    Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, EnumIter,
+    Debug,
+    Copy,
+    Clone,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    EnumIter,
+    Default,
 )]
 pub enum StructureType {
     ActualConstraint,
@@ -12,6 +23,7 @@ pub enum StructureType {
     CodeList,
     ConceptScheme,
     ContentConstraint,
+    #[default]
     DataFlow,
     DataStructure,
     AgencyScheme,
@@ -35,8 +47,21 @@ impl Display for StructureType {
     }
 }
 
-impl Default for StructureType {
-    fn default() -> Self {
-        Self::DataFlow
+impl StructureType {
+    /// The name of the structure type in SDMX 3.0 urls, where content and actual
+    /// constraints are both data constraints.
+    pub fn sdmx_3_0_name(&self) -> &'static str {
+        match self {
+            Self::ActualConstraint => "dataconstraint",
+            Self::Categorisation => "categorisation",
+            Self::CategoryScheme => "categoryscheme",
+            Self::CodeList => "codelist",
+            Self::ConceptScheme => "conceptscheme",
+            Self::ContentConstraint => "dataconstraint",
+            Self::DataFlow => "dataflow",
+            Self::DataStructure => "datastructure",
+            Self::AgencyScheme => "agencyscheme",
+            Self::HierarchicalCodelist => "hierarchy",
+        }
     }
 }
@@ -0,0 +1,307 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display, Formatter},
+};
+
+#[cfg(feature = "decimal")]
+use bigdecimal::BigDecimal;
+
+use crate::{
+    config::Config,
+    error_code::ErrorCode,
+    models::derived::{
+        data::Data,
+        data_point::DataPoint,
+        data_sets::DataSets,
+        flat_observation::{FlatObservation, FlatObservations},
+        sdmx_response::SdmxResponse,
+        structure::Structure,
+    },
+    result::Result,
+};
+
+use super::period::Period;
+
+/// The dot separated codes of a series, in dimension order, such as `1.10001.10.8.Q`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SeriesKey {
+    inner: Box<str>,
+}
+
+impl SeriesKey {
+    pub fn codes(&self) -> impl Iterator<Item = &str> {
+        self.inner.split('.')
+    }
+}
+
+impl<'a> FromIterator<&'a str> for SeriesKey {
+    fn from_iter<T: IntoIterator<Item = &'a str>>(iter: T) -> Self {
+        Self {
+            inner: iter.into_iter().collect::<Vec<_>>().join(".").into(),
+        }
+    }
+}
+
+impl Display for SeriesKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl AsRef<str> for SeriesKey {
+    fn as_ref(&self) -> &str {
+        &self.inner
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeSeriesObservation {
+    pub period: Period,
+    pub value: Option<f64>,
+    pub data_point: Option<DataPoint>,
+    pub attributes: HashMap<Box<str>, Box<str>>,
+}
+
+/// The observations of one series, ordered by period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeSeries {
+    key: SeriesKey,
+    dimensions: HashMap<Box<str>, Box<str>>,
+    attributes: HashMap<Box<str>, Box<str>>,
+    observations: Box<[TimeSeriesObservation]>,
+}
+
+impl TimeSeries {
+    pub fn key(&self) -> &SeriesKey {
+        &self.key
+    }
+
+    pub fn dimensions(&self) -> &HashMap<Box<str>, Box<str>> {
+        &self.dimensions
+    }
+
+    pub fn dimension(&self, id: &str) -> Option<&str> {
+        self.dimensions.get(id).map(AsRef::as_ref)
+    }
+
+    pub fn attributes(&self) -> &HashMap<Box<str>, Box<str>> {
+        &self.attributes
+    }
+
+    pub fn attribute(&self, id: &str) -> Option<&str> {
+        self.attributes.get(id).map(AsRef::as_ref)
+    }
+
+    pub fn observations(&self) -> &[TimeSeriesObservation] {
+        &self.observations
+    }
+
+    /// The periods and values of the series, skipping missing values.
+    pub fn values(&self) -> Vec<(Period, f64)> {
+        self.observations
+            .iter()
+            .filter_map(|observation| Some((observation.period, observation.value?)))
+            .collect()
+    }
+
+    /// The periods and exact values of the series, skipping missing values.
+    #[cfg(feature = "decimal")]
+    pub fn decimal_values(&self) -> Vec<(Period, BigDecimal)> {
+        self.observations
+            .iter()
+            .filter_map(|observation| {
+                Some((
+                    observation.period,
+                    observation.data_point.as_ref()?.as_decimal()?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Whether the series has every given dimension code, such as `[("REGION", "8")]`.
+    pub fn matches(&self, codes: &[(&str, &str)]) -> bool {
+        codes
+            .iter()
+            .all(|(id, code)| self.dimension(id) == Some(*code))
+    }
+}
+
+/// Every series of a data message, ordered by series key.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TimeSeriesCollection {
+    series: Box<[TimeSeries]>,
+}
+
+impl TimeSeriesCollection {
+    pub fn len(&self) -> usize {
+        self.series.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.series.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, TimeSeries> {
+        self.series.iter()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&TimeSeries> {
+        self.series.iter().find(|series| series.key.as_ref() == key)
+    }
+
+    /// The first series with every given dimension code.
+    pub fn find(&self, codes: &[(&str, &str)]) -> Option<&TimeSeries> {
+        self.series.iter().find(|series| series.matches(codes))
+    }
+
+    /// Every series with every given dimension code.
+    pub fn filter<'a>(
+        &'a self,
+        codes: &'a [(&'a str, &'a str)],
+    ) -> impl Iterator<Item = &'a TimeSeries> {
+        self.series.iter().filter(|series| series.matches(codes))
+    }
+}
+
+impl<'a> IntoIterator for &'a TimeSeriesCollection {
+    type Item = &'a TimeSeries;
+    type IntoIter = std::slice::Iter<'a, TimeSeries>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for TimeSeriesCollection {
+    type Item = TimeSeries;
+    type IntoIter = std::vec::IntoIter<TimeSeries>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.series.into_vec().into_iter()
+    }
+}
+
+/// Groups observations into series with their periods parsed, in order of first
+/// appearance and then by period.
+fn group<'a, I>(structure: &Structure, flat_observations: I) -> Result<Vec<TimeSeries>>
+where
+    I: IntoIterator<Item = FlatObservation<'a>>,
+{
+    let key_length = structure.dimensions.series.len();
+    let series_attributes = structure
+        .attributes
+        .series
+        .iter()
+        .map(|attribute| attribute.id.as_ref())
+        .collect::<HashSet<_>>();
+
+    let mut series = Vec::<TimeSeries>::new();
+    let mut observations = Vec::<Vec<TimeSeriesObservation>>::new();
+    let mut positions = HashMap::<&str, usize>::new();
+
+    for observation in flat_observations {
+        let position = *positions.entry(observation.series_key).or_insert_with(|| {
+            series.push(TimeSeries {
+                key: observation.dimensions[..key_length.min(observation.dimensions.len())]
+                    .iter()
+                    .map(|dimension| dimension.code)
+                    .collect(),
+                dimensions: observation
+                    .dimensions
+                    .iter()
+                    .map(|dimension| (dimension.id.into(), dimension.code.into()))
+                    .collect(),
+                attributes: observation
+                    .attributes
+                    .iter()
+                    .filter(|attribute| series_attributes.contains(attribute.id))
+                    .map(|attribute| (attribute.id.into(), attribute.code.into()))
+                    .collect(),
+                observations: Box::default(),
+            });
+            observations.push(Vec::new());
+            series.len() - 1
+        });
+
+        let time_period = observation
+            .time_period
+            .ok_or(ErrorCode::MissingExpectedValueOnField(
+                Config::TIME_PERIOD_DIMENSION.into(),
+            ))?;
+
+        observations[position].push(TimeSeriesObservation {
+            period: time_period.parse()?,
+            value: observation.value,
+            data_point: observation.data_point.cloned(),
+            attributes: observation
+                .attributes
+                .iter()
+                .filter(|attribute| !series_attributes.contains(attribute.id))
+                .map(|attribute| (attribute.id.into(), attribute.code.into()))
+                .collect(),
+        });
+    }
+
+    for (series, mut observations) in series.iter_mut().zip(observations) {
+        observations.sort_by_key(|observation| observation.period);
+        series.observations = observations.into();
+    }
+
+    Ok(series)
+}
+
+impl TimeSeries {
+    pub(crate) fn new(
+        key: SeriesKey,
+        dimensions: HashMap<Box<str>, Box<str>>,
+        attributes: HashMap<Box<str>, Box<str>>,
+        mut observations: Vec<TimeSeriesObservation>,
+    ) -> Self {
+        observations.sort_by_key(|observation| observation.period);
+
+        Self {
+            key,
+            dimensions,
+            attributes,
+            observations: observations.into(),
+        }
+    }
+
+    /// Resolves one series of an SDMX-JSON data set against `structure`, or `None` when
+    /// it has no observations.
+    pub(crate) fn from_data(structure: &Structure, key: &str, data: &Data) -> Result<Option<Self>> {
+        let observations = FlatObservations::series(structure, key, data);
+
+        Ok(group(structure, observations)?.pop())
+    }
+}
+
+impl DataSets {
+    /// Groups the observations into series with their periods parsed, ordered by
+    /// series key and then period. Data sets are resolved against their own structure,
+    /// with the series of each structure in turn.
+    pub fn time_series(&self) -> Result<TimeSeriesCollection> {
+        self.structure
+            .as_ref()
+            .ok_or(ErrorCode::MissingExpectedOptionalField("structure".into()))?;
+
+        let mut series = Vec::new();
+
+        for (structure, data_sets) in self.by_structure()? {
+            series.extend(group(
+                structure,
+                FlatObservations::new(structure, data_sets),
+            )?);
+        }
+
+        Ok(TimeSeriesCollection {
+            series: series.into(),
+        })
+    }
+}
+
+impl SdmxResponse<DataSets> {
+    pub fn time_series(&self) -> Result<TimeSeriesCollection> {
+        self.data.time_series()
+    }
+}
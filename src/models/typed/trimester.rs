@@ -0,0 +1,56 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+use crate::{error_code::ErrorCode, result::Result};
+
+use super::period;
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    EnumIter,
+    Default,
+)]
+pub enum Trimester {
+    #[default]
+    First,
+    Second,
+    Third,
+}
+
+impl fmt::Display for Trimester {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let trimester_str = match self {
+            Self::First => "T1",
+            Self::Second => "T2",
+            Self::Third => "T3",
+        };
+        write!(f, "{}", trimester_str)
+    }
+}
+
+impl TryFrom<&str> for Trimester {
+    type Error = ErrorCode;
+
+    fn try_from(str: &str) -> Result<Self> {
+        period::parse_variant(str)
+    }
+}
+
+impl FromStr for Trimester {
+    type Err = ErrorCode;
+
+    fn from_str(str: &str) -> Result<Self> {
+        Self::try_from(str)
+    }
+}
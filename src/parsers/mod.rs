@@ -0,0 +1,5 @@
+pub(crate) mod raw_data;
+pub mod sdmx_csv;
+pub mod sdmx_json;
+#[cfg(feature = "xml")]
+pub mod sdmx_ml;
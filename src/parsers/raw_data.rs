@@ -0,0 +1,296 @@
+use std::collections::HashMap;
+
+use crate::{
+    config::Config,
+    error_code::ErrorCode,
+    models::derived::{
+        attributes::Attributes,
+        data::Data,
+        data_point::DataPoint,
+        data_sets::{DataSet, DataSets},
+        dimensions::Dimensions,
+        meta::Meta,
+        names::Names,
+        observation::Observation,
+        relationship::Relationship,
+        sdmx_response::SdmxResponse,
+        sender::Sender,
+        series::Series,
+        structure::Structure,
+        value::Value,
+    },
+    result::Result,
+};
+
+#[derive(Default)]
+pub(crate) struct Header {
+    pub(crate) id: Box<str>,
+    pub(crate) test: bool,
+    pub(crate) prepared: Box<str>,
+    pub(crate) sender: Box<str>,
+    pub(crate) structure_id: Box<str>,
+    pub(crate) dimension_at_observation: Option<Box<str>>,
+}
+
+#[derive(Default)]
+pub(crate) struct RawObservation {
+    pub(crate) time: Box<str>,
+    pub(crate) value: Option<Box<str>>,
+    pub(crate) attributes: Vec<(Box<str>, Box<str>)>,
+}
+
+#[derive(Default)]
+pub(crate) struct RawSeries {
+    pub(crate) key: Vec<(Box<str>, Box<str>)>,
+    pub(crate) attributes: Vec<(Box<str>, Box<str>)>,
+    pub(crate) observations: Vec<RawObservation>,
+}
+
+/// A data message read from SDMX-ML or SDMX-CSV, before it is normalised into the
+/// SDMX-JSON data model.
+#[derive(Default)]
+pub(crate) struct RawMessage {
+    pub(crate) header: Header,
+    pub(crate) action: Box<str>,
+    pub(crate) series: Vec<RawSeries>,
+}
+
+/// The distinct values of one component in order of first appearance.
+#[derive(Default)]
+struct Codes {
+    id: Box<str>,
+    values: Vec<Box<str>>,
+    positions: HashMap<Box<str>, usize>,
+}
+
+impl Codes {
+    fn new(id: &str) -> Self {
+        Self {
+            id: id.into(),
+            ..Default::default()
+        }
+    }
+
+    fn index(&mut self, value: &str) -> usize {
+        if let Some(index) = self.positions.get(value) {
+            return *index;
+        }
+        self.values.push(value.into());
+        self.positions.insert(value.into(), self.values.len() - 1);
+        self.values.len() - 1
+    }
+
+    fn values(&self) -> Box<[Value]> {
+        self.values
+            .iter()
+            .map(|value| Value {
+                id: value.clone(),
+                name: value.clone(),
+                names: Names { en: value.clone() },
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    fn into_series(self, key_position: Option<u8>, relationship: Option<Relationship>) -> Series {
+        Series {
+            values: self.values(),
+            name: self.id.clone(),
+            names: Names {
+                en: self.id.clone(),
+            },
+            id: self.id,
+            key_position,
+            relationship,
+            ..Default::default()
+        }
+    }
+
+    fn into_observation(
+        self,
+        key_position: Option<i64>,
+        relationship: Option<Relationship>,
+    ) -> Observation {
+        Observation {
+            values: self.values(),
+            name: self.id.clone(),
+            names: Names {
+                en: self.id.clone(),
+            },
+            id: self.id,
+            key_position,
+            relationship,
+            ..Default::default()
+        }
+    }
+}
+
+/// Finds the codes for `id`, adding them in order of first appearance when missing.
+fn codes_for<'c>(codes: &'c mut Vec<Codes>, id: &str) -> &'c mut Codes {
+    match codes.iter().position(|c| c.id.as_ref() == id) {
+        Some(index) => &mut codes[index],
+        None => {
+            codes.push(Codes::new(id));
+            codes.last_mut().expect("codes were just pushed")
+        }
+    }
+}
+
+pub(crate) fn into_response(message: RawMessage) -> Result<SdmxResponse<DataSets>> {
+    let mut series_dimensions = Vec::<Codes>::new();
+    let mut series_attributes = Vec::<Codes>::new();
+    let mut observation_attributes = Vec::<Codes>::new();
+
+    let time_dimension = message
+        .header
+        .dimension_at_observation
+        .as_deref()
+        .unwrap_or(Config::TIME_PERIOD_DIMENSION);
+    let mut time_codes = Codes::new(time_dimension);
+
+    // Register every component first so all series share the same component order.
+    for series in &message.series {
+        for (id, _) in &series.key {
+            codes_for(&mut series_dimensions, id);
+        }
+        for (id, _) in &series.attributes {
+            codes_for(&mut series_attributes, id);
+        }
+        for observation in &series.observations {
+            for (id, _) in &observation.attributes {
+                codes_for(&mut observation_attributes, id);
+            }
+        }
+    }
+
+    let mut data_set_series = HashMap::new();
+
+    for series in message.series {
+        let key = series_dimensions
+            .iter_mut()
+            .map(|codes| {
+                let value = series
+                    .key
+                    .iter()
+                    .find(|(id, _)| *id == codes.id)
+                    .map(|(_, value)| value)
+                    .ok_or_else(|| ErrorCode::MissingExpectedValueOnField(codes.id.clone()))?;
+                Ok(codes.index(value).to_string())
+            })
+            .collect::<Result<Vec<_>>>()?
+            .join(":");
+
+        let attributes = series_attributes
+            .iter_mut()
+            .map(|codes| {
+                series
+                    .attributes
+                    .iter()
+                    .find(|(id, _)| *id == codes.id)
+                    .map(|(_, value)| codes.index(value) as i64)
+            })
+            .collect();
+
+        let observations = series
+            .observations
+            .into_iter()
+            .map(|observation| {
+                let time = time_codes.index(&observation.time).to_string();
+
+                let values = std::iter::once(observation.value.as_deref().map(DataPoint::from))
+                    .chain(observation_attributes.iter_mut().map(|codes| {
+                        observation
+                            .attributes
+                            .iter()
+                            .find(|(id, _)| *id == codes.id)
+                            .map(|(_, value)| {
+                                DataPoint::from(codes.index(value).to_string().as_str())
+                            })
+                    }))
+                    .collect();
+
+                (time.into(), values)
+            })
+            .collect();
+
+        data_set_series.insert(
+            key.into(),
+            Data {
+                attributes,
+                observations,
+                ..Default::default()
+            },
+        );
+    }
+
+    let dimension_ids = series_dimensions
+        .iter()
+        .map(|codes| codes.id.clone())
+        .collect::<Box<[_]>>();
+    let time_position = dimension_ids.len() as i64;
+
+    let structure = Structure {
+        name: message.header.structure_id.clone(),
+        names: Names {
+            en: message.header.structure_id.clone(),
+        },
+        dimensions: Dimensions {
+            series: series_dimensions
+                .into_iter()
+                .enumerate()
+                .map(|(position, codes)| codes.into_series(Some(position as u8), None))
+                .collect(),
+            observation: Box::new([time_codes.into_observation(Some(time_position), None)]),
+            ..Default::default()
+        },
+        attributes: Attributes {
+            series: series_attributes
+                .into_iter()
+                .map(|codes| {
+                    let relationship = Relationship {
+                        dimensions: Some(dimension_ids.clone()),
+                        primary_measure: None,
+                    };
+                    codes.into_series(None, Some(relationship))
+                })
+                .collect(),
+            observation: observation_attributes
+                .into_iter()
+                .map(|codes| {
+                    let relationship = Relationship {
+                        dimensions: None,
+                        primary_measure: Some(Config::PRIMARY_MEASURE.into()),
+                    };
+                    codes.into_observation(None, Some(relationship))
+                })
+                .collect(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let header = message.header;
+
+    Ok(SdmxResponse {
+        data: DataSets {
+            data_sets: Box::new([DataSet {
+                action: message.action,
+                series: data_set_series,
+                ..Default::default()
+            }]),
+            structure: Some(structure),
+            structures: Box::default(),
+        },
+        meta: Meta {
+            id: header.id,
+            prepared: header.prepared,
+            test: header.test,
+            sender: Sender {
+                id: header.sender,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        structure: None,
+    })
+}
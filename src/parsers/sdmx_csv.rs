@@ -0,0 +1,286 @@
+use std::{collections::HashMap, io::Read};
+
+use csv::StringRecord;
+
+use crate::{
+    config::Config,
+    error_code::ErrorCode,
+    models::{
+        derived::{
+            data_point::DataPoint,
+            data_sets::DataSets,
+            sdmx_csv::{SdmxCsv, SdmxCsvColumns, SdmxCsvRow},
+            sdmx_response::SdmxResponse,
+        },
+        typed::time_series::{TimeSeries, TimeSeriesObservation},
+    },
+    parsers::raw_data::{self, RawMessage, RawObservation, RawSeries},
+    result::Result,
+};
+
+/// Columns identifying the dataflow of a row in SDMX-CSV 1.0 and 2.0.
+const DATAFLOW_COLUMNS: [&str; 2] = ["DATAFLOW", "STRUCTURE_ID"];
+/// Columns describing the message rather than a component.
+const MESSAGE_COLUMNS: [&str; 4] = ["DATAFLOW", "STRUCTURE", "STRUCTURE_ID", "ACTION"];
+
+/// Where each part of a row is found in a record.
+#[derive(Default)]
+struct Layout {
+    dataflow: Option<usize>,
+    dimensions: Vec<usize>,
+    time_period: Option<usize>,
+    obs_value: Option<usize>,
+    attributes: Vec<usize>,
+}
+
+/// Reads SDMX-CSV 1.0 or 2.0 records one row at a time.
+///
+/// Component columns before `OBS_VALUE` are read as dimensions and those after it as
+/// attributes, matching the column order of the dataflow's data structure. Headers
+/// with labels, such as `MEASURE: Measure`, are read by their id.
+pub struct SdmxCsvReader<R> {
+    reader: csv::Reader<R>,
+    columns: SdmxCsvColumns,
+    layout: Layout,
+    record: StringRecord,
+}
+
+impl<R> SdmxCsvReader<R>
+where
+    R: Read,
+{
+    pub fn new(reader: R) -> Result<Self> {
+        let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(reader);
+
+        let ids = reader
+            .headers()?
+            .iter()
+            .map(|header| header.split(':').next().unwrap_or_default().trim())
+            .collect::<Vec<_>>();
+
+        let obs_value = ids.iter().position(|id| *id == Config::PRIMARY_MEASURE);
+        let mut layout = Layout {
+            dataflow: ids.iter().position(|id| DATAFLOW_COLUMNS.contains(id)),
+            time_period: ids
+                .iter()
+                .position(|id| *id == Config::TIME_PERIOD_DIMENSION),
+            obs_value,
+            ..Default::default()
+        };
+        let mut columns = (Vec::new(), Vec::new());
+
+        for (index, id) in ids.iter().enumerate() {
+            if MESSAGE_COLUMNS.contains(id)
+                || Some(index) == layout.time_period
+                || Some(index) == layout.obs_value
+            {
+                continue;
+            }
+
+            if obs_value.is_none_or(|obs_value| index < obs_value) {
+                layout.dimensions.push(index);
+                columns.0.push((*id).into());
+            } else {
+                layout.attributes.push(index);
+                columns.1.push((*id).into());
+            }
+        }
+
+        Ok(Self {
+            reader,
+            columns: SdmxCsvColumns {
+                dimensions: columns.0.into(),
+                attributes: columns.1.into(),
+            },
+            layout,
+            record: StringRecord::new(),
+        })
+    }
+
+    pub fn columns(&self) -> &SdmxCsvColumns {
+        &self.columns
+    }
+
+    fn row(&self) -> SdmxCsvRow {
+        let field = |index: usize| self.record.get(index).unwrap_or_default();
+        let optional = |index: Option<usize>| {
+            index
+                .map(field)
+                .filter(|value| !value.is_empty())
+                .map(Box::<str>::from)
+        };
+
+        SdmxCsvRow {
+            dataflow: optional(self.layout.dataflow).unwrap_or_default(),
+            dimensions: self
+                .layout
+                .dimensions
+                .iter()
+                .map(|index| field(*index).into())
+                .collect(),
+            time_period: optional(self.layout.time_period),
+            obs_value: self
+                .layout
+                .obs_value
+                .map(|index| DataPoint::from(field(index))),
+            attributes: self
+                .layout
+                .attributes
+                .iter()
+                .map(|index| optional(Some(*index)))
+                .collect(),
+        }
+    }
+}
+
+impl<R> Iterator for SdmxCsvReader<R>
+where
+    R: Read,
+{
+    type Item = Result<SdmxCsvRow>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.reader.read_record(&mut self.record) {
+            Ok(true) => Some(Ok(self.row())),
+            Ok(false) => None,
+            Err(e) => Some(Err(e.into())),
+        }
+    }
+}
+
+/// Parses an SDMX-CSV message into its columns and typed rows.
+pub fn parse_rows(bytes: &[u8]) -> Result<SdmxCsv> {
+    let reader = SdmxCsvReader::new(bytes)?;
+    let columns = reader.columns().clone();
+    let rows = reader.collect::<Result<_>>()?;
+
+    Ok(SdmxCsv { columns, rows })
+}
+
+/// Groups rows into series by their dimension values, in order of first appearance.
+/// Every attribute is attached to the observation, as SDMX-CSV does not say which
+/// attributes belong to the series.
+impl From<SdmxCsv> for RawMessage {
+    fn from(csv: SdmxCsv) -> Self {
+        let SdmxCsv { columns, rows } = csv;
+
+        let mut message = RawMessage::default();
+        let mut positions = HashMap::<Box<[Box<str>]>, usize>::new();
+
+        for row in rows.into_vec() {
+            if message.header.structure_id.is_empty() {
+                message.header.structure_id = row.dataflow.clone();
+            }
+
+            let position = *positions.entry(row.dimensions.clone()).or_insert_with(|| {
+                message.series.push(RawSeries {
+                    key: columns
+                        .dimensions
+                        .iter()
+                        .cloned()
+                        .zip(row.dimensions.iter().cloned())
+                        .collect(),
+                    ..Default::default()
+                });
+                message.series.len() - 1
+            });
+
+            message.series[position].observations.push(RawObservation {
+                time: row.time_period.unwrap_or_default(),
+                value: row.obs_value.map(|value| value.as_ref().into()),
+                attributes: columns
+                    .attributes
+                    .iter()
+                    .zip(row.attributes.into_vec())
+                    .filter_map(|(id, value)| Some((id.clone(), value?)))
+                    .collect(),
+            });
+        }
+
+        message
+    }
+}
+
+/// Parses an SDMX-CSV message into the same model as the SDMX-JSON messages.
+pub fn parse_data(bytes: &[u8]) -> Result<SdmxResponse<DataSets>> {
+    raw_data::into_response(parse_rows(bytes)?.into())
+}
+
+/// The series of consecutive rows with the same dimension values.
+fn time_series(
+    columns: &SdmxCsvColumns,
+    dimensions: &[Box<str>],
+    rows: Vec<SdmxCsvRow>,
+) -> Result<TimeSeries> {
+    let observations = rows
+        .into_iter()
+        .map(|row| {
+            let time_period = row
+                .time_period
+                .ok_or(ErrorCode::MissingExpectedValueOnField(
+                    Config::TIME_PERIOD_DIMENSION.into(),
+                ))?;
+
+            Ok(TimeSeriesObservation {
+                period: time_period.parse()?,
+                value: row.obs_value.as_ref().and_then(DataPoint::as_f64),
+                data_point: row.obs_value,
+                attributes: columns
+                    .attributes
+                    .iter()
+                    .cloned()
+                    .zip(row.attributes.into_vec())
+                    .filter_map(|(id, value)| Some((id, value?)))
+                    .collect(),
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(TimeSeries::new(
+        dimensions.iter().map(AsRef::as_ref).collect(),
+        columns
+            .dimensions
+            .iter()
+            .cloned()
+            .zip(dimensions.iter().cloned())
+            .collect(),
+        HashMap::new(),
+        observations,
+    ))
+}
+
+/// Reads an SDMX-CSV message from `reader`, handing each series to `on_series` once its
+/// last row is read, so that only one series is held at a time.
+///
+/// Consecutive rows with the same dimension values form a series, and every attribute
+/// is attached to the observations. A series whose rows are not consecutive is handed
+/// over once per run of rows. An error from `on_series` stops reading and is returned.
+pub fn read_series<R, F>(reader: R, mut on_series: F) -> Result<()>
+where
+    R: Read,
+    F: FnMut(TimeSeries) -> Result<()>,
+{
+    let mut reader = SdmxCsvReader::new(reader)?;
+    let columns = reader.columns().clone();
+    let mut current = None::<(Box<[Box<str>]>, Vec<SdmxCsvRow>)>;
+
+    for row in &mut reader {
+        let row = row?;
+
+        match &mut current {
+            Some((dimensions, rows)) if *dimensions == row.dimensions => rows.push(row),
+            _ => {
+                let next = (row.dimensions.clone(), vec![row]);
+                if let Some((dimensions, rows)) = current.replace(next) {
+                    on_series(time_series(&columns, &dimensions, rows)?)?;
+                }
+            }
+        }
+    }
+
+    if let Some((dimensions, rows)) = current {
+        on_series(time_series(&columns, &dimensions, rows)?)?;
+    }
+
+    Ok(())
+}
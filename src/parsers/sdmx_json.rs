@@ -0,0 +1,480 @@
+use std::{
+    fmt,
+    io::{BufReader, Read},
+    mem,
+};
+
+use serde::{
+    de::{self, DeserializeOwned, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer,
+};
+use serde_json::value::RawValue;
+
+use crate::{
+    error_code::ErrorCode,
+    models::{
+        derived::{
+            data::Data, data_message_v2::DataMessageV2, data_sets::DataSets,
+            sdmx_response::SdmxResponse, structure::Structure,
+        },
+        typed::{sdmx_json_version::SdmxJsonVersion, time_series::TimeSeries},
+    },
+    result::Result,
+};
+
+#[derive(Deserialize)]
+struct SchemaProbe {
+    meta: Option<MetaProbe>,
+}
+
+#[derive(Deserialize)]
+struct MetaProbe {
+    schema: Option<Box<str>>,
+}
+
+pub fn deserialize<T>(bytes: &[u8]) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    match serde_json::from_slice(bytes) {
+        Ok(data) => Ok(data),
+        Err(e) => {
+            // Get a snippet of the text around the error location
+            let error_position = e.column();
+            let start = error_position.saturating_sub(10);
+            let end = (error_position + 10).min(bytes.len());
+            let snippet = String::from_utf8_lossy(bytes.get(start..end).unwrap_or_default());
+
+            // Return the enhanced error
+            Err(ErrorCode::JsonSliceDeserialization(e, snippet.into()))
+        }
+    }
+}
+
+/// Reads the message format version from `meta.schema`, defaulting to SDMX-JSON 1.0.
+pub fn detect_version(bytes: &[u8]) -> Result<SdmxJsonVersion> {
+    let probe: SchemaProbe = deserialize(bytes)?;
+
+    Ok(probe
+        .meta
+        .and_then(|meta| meta.schema)
+        .map(|schema| SdmxJsonVersion::from_schema(&schema))
+        .unwrap_or_default())
+}
+
+/// Parses an SDMX-JSON 1.0 or 2.0 data message into the common data model.
+pub fn parse_data(bytes: &[u8]) -> Result<SdmxResponse<DataSets>> {
+    match detect_version(bytes)? {
+        SdmxJsonVersion::V1 => deserialize(bytes),
+        SdmxJsonVersion::V2 => deserialize::<DataMessageV2>(bytes)?.try_into(),
+    }
+}
+
+/// The state of `read_series` while the message is deserialised.
+struct SeriesReader<F> {
+    on_series: F,
+    /// The message format version, when `meta` is read before the data.
+    version: Option<SdmxJsonVersion>,
+    structures: Vec<Structure>,
+    /// Series read before the structure they refer to, by structure index, kept as the
+    /// JSON text they were read from.
+    pending: Vec<(usize, Box<str>, Box<RawValue>)>,
+    /// The structure index of the data set being read, once known.
+    structure: Option<usize>,
+    /// Series of the data set being read, read before its structure index.
+    unresolved: Vec<(Box<str>, Box<RawValue>)>,
+    /// The error returned by `on_series`, kept so it is not flattened into a JSON error.
+    error: Option<ErrorCode>,
+}
+
+impl<F> SeriesReader<F>
+where
+    F: FnMut(TimeSeries) -> Result<()>,
+{
+    fn series(&mut self, structure: usize, key: &str, data: &Data) -> Result<()> {
+        let structure = self
+            .structures
+            .get(structure)
+            .ok_or(ErrorCode::MissingExpectedOptionalField("structure".into()))?;
+
+        match TimeSeries::from_data(structure, key, data)? {
+            Some(series) => (self.on_series)(series),
+            None => Ok(()),
+        }
+    }
+
+    /// Parses a series held as JSON text and hands it over.
+    fn held_series(&mut self, structure: usize, key: &str, data: &RawValue) -> Result<()> {
+        let data = serde_json::from_str::<Data>(data.get())?;
+        self.series(structure, key, &data)
+    }
+
+    /// The structure index of the data set being read, when that structure has been read.
+    fn ready(&self) -> Option<usize> {
+        self.structure
+            .filter(|structure| *structure < self.structures.len())
+    }
+
+    fn push<E>(&mut self, key: Box<str>, data: Box<RawValue>) -> std::result::Result<(), E>
+    where
+        E: de::Error,
+    {
+        match (self.structure, self.ready()) {
+            (None, _) => self.unresolved.push((key, data)),
+            (Some(structure), None) => self.pending.push((structure, key, data)),
+            (_, Some(structure)) => self
+                .held_series(structure, &key, &data)
+                .map_err(|e| self.fail(e))?,
+        }
+
+        Ok(())
+    }
+
+    /// Sets the structure index of the data set being read, resolving the series read
+    /// before it.
+    fn resolve<E>(&mut self, structure: usize) -> std::result::Result<(), E>
+    where
+        E: de::Error,
+    {
+        self.structure = Some(structure);
+
+        for (key, data) in mem::take(&mut self.unresolved) {
+            self.push(key, data)?;
+        }
+
+        Ok(())
+    }
+
+    fn flush<E>(&mut self) -> std::result::Result<(), E>
+    where
+        E: de::Error,
+    {
+        let pending = mem::take(&mut self.pending);
+        let (ready, waiting) = pending
+            .into_iter()
+            .partition::<Vec<_>, _>(|(structure, ..)| *structure < self.structures.len());
+        self.pending = waiting;
+
+        for (structure, key, data) in ready {
+            self.held_series(structure, &key, &data)
+                .map_err(|e| self.fail(e))?;
+        }
+
+        Ok(())
+    }
+
+    fn fail<E>(&mut self, error: ErrorCode) -> E
+    where
+        E: de::Error,
+    {
+        let message = error.to_string();
+        self.error = Some(error);
+        E::custom(message)
+    }
+}
+
+/// Skips every entry of a map other than those `visit` reads.
+fn for_each_key<'de, A, V>(mut map: A, mut visit: V) -> std::result::Result<(), A::Error>
+where
+    A: MapAccess<'de>,
+    V: FnMut(&str, &mut A) -> std::result::Result<bool, A::Error>,
+{
+    while let Some(key) = map.next_key::<Box<str>>()? {
+        if !visit(&key, &mut map)? {
+            map.next_value::<IgnoredAny>()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads the top level of the message, of which only the version and `data` are kept.
+struct MessageSeed<'r, F>(&'r mut SeriesReader<F>);
+
+impl<'de, F> DeserializeSeed<'de> for MessageSeed<'_, F>
+where
+    F: FnMut(TimeSeries) -> Result<()>,
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> std::result::Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, F> Visitor<'de> for MessageSeed<'_, F>
+where
+    F: FnMut(TimeSeries) -> Result<()>,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an SDMX-JSON data message")
+    }
+
+    fn visit_map<A>(self, map: A) -> std::result::Result<(), A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let reader = self.0;
+
+        for_each_key(map, |key, map| {
+            match key {
+                "meta" => {
+                    let meta = map.next_value::<Option<MetaProbe>>()?;
+                    reader.version = Some(
+                        meta.and_then(|meta| meta.schema)
+                            .map(|schema| SdmxJsonVersion::from_schema(&schema))
+                            .unwrap_or_default(),
+                    );
+                }
+                "data" => map.next_value_seed(DataSeed(&mut *reader))?,
+                _ => return Ok(false),
+            }
+
+            Ok(true)
+        })
+    }
+}
+
+/// Reads the structures and data sets of `data`, in either order.
+struct DataSeed<'r, F>(&'r mut SeriesReader<F>);
+
+impl<'de, F> DeserializeSeed<'de> for DataSeed<'_, F>
+where
+    F: FnMut(TimeSeries) -> Result<()>,
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> std::result::Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, F> Visitor<'de> for DataSeed<'_, F>
+where
+    F: FnMut(TimeSeries) -> Result<()>,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("the data of an SDMX-JSON data message")
+    }
+
+    fn visit_map<A>(self, map: A) -> std::result::Result<(), A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let reader = self.0;
+
+        for_each_key(map, |key, map| {
+            match key {
+                "structure" => {
+                    let structure = map.next_value::<Structure>()?;
+                    reader.structures.push(structure);
+                    reader.flush()?;
+                }
+                "structures" => {
+                    let structures = map.next_value::<Vec<Structure>>()?;
+                    reader.structures.extend(structures);
+                    reader.flush()?;
+                }
+                "dataSets" => map.next_value_seed(DataSetsSeed(&mut *reader))?,
+                _ => return Ok(false),
+            }
+
+            Ok(true)
+        })?;
+
+        if reader.pending.is_empty() {
+            Ok(())
+        } else {
+            Err(reader.fail(ErrorCode::MissingExpectedOptionalField("structure".into())))
+        }
+    }
+}
+
+struct DataSetsSeed<'r, F>(&'r mut SeriesReader<F>);
+
+impl<'de, F> DeserializeSeed<'de> for DataSetsSeed<'_, F>
+where
+    F: FnMut(TimeSeries) -> Result<()>,
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> std::result::Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, F> Visitor<'de> for DataSetsSeed<'_, F>
+where
+    F: FnMut(TimeSeries) -> Result<()>,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a list of data sets")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> std::result::Result<(), A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        while seq.next_element_seed(DataSetSeed(&mut *self.0))?.is_some() {}
+
+        Ok(())
+    }
+}
+
+/// Reads the series of one data set, handing each over as soon as it is read and its
+/// structure is known.
+struct DataSetSeed<'r, F>(&'r mut SeriesReader<F>);
+
+impl<'de, F> DeserializeSeed<'de> for DataSetSeed<'_, F>
+where
+    F: FnMut(TimeSeries) -> Result<()>,
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> std::result::Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, F> Visitor<'de> for DataSetSeed<'_, F>
+where
+    F: FnMut(TimeSeries) -> Result<()>,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a data set")
+    }
+
+    fn visit_map<A>(self, map: A) -> std::result::Result<(), A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let reader = self.0;
+        // SDMX-JSON 1.0 data sets have no structure index, as the message has a single
+        // structure. In 2.0 the index may come after the series.
+        reader.structure = match reader.version {
+            Some(SdmxJsonVersion::V1) => Some(0),
+            _ => None,
+        };
+
+        for_each_key(map, |key, map| {
+            match key {
+                "structure" => reader.resolve(map.next_value()?)?,
+                "series" => map.next_value_seed(SeriesSeed(&mut *reader))?,
+                _ => return Ok(false),
+            }
+
+            Ok(true)
+        })?;
+
+        match reader.structure {
+            Some(_) => Ok(()),
+            None => reader.resolve(0),
+        }
+    }
+}
+
+struct SeriesSeed<'r, F>(&'r mut SeriesReader<F>);
+
+impl<'de, F> DeserializeSeed<'de> for SeriesSeed<'_, F>
+where
+    F: FnMut(TimeSeries) -> Result<()>,
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> std::result::Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, F> Visitor<'de> for SeriesSeed<'_, F>
+where
+    F: FnMut(TimeSeries) -> Result<()>,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a map of series keys to series")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> std::result::Result<(), A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let reader = self.0;
+
+        // Series whose structure is not yet known are held as their JSON text, which
+        // takes less memory than the parsed series.
+        while let Some(key) = map.next_key::<Box<str>>()? {
+            match reader.ready() {
+                Some(structure) => {
+                    let data = map.next_value::<Data>()?;
+                    reader
+                        .series(structure, &key, &data)
+                        .map_err(|e| reader.fail(e))?;
+                }
+                None => reader.push(key, map.next_value::<Box<RawValue>>()?)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads an SDMX-JSON 1.0 or 2.0 data message from `reader`, handing each series to
+/// `on_series` as soon as it is read rather than holding the whole message.
+///
+/// Series are resolved against their data set's structure, so memory only stays bounded
+/// when that structure, and the data set's `structure` index, come before its series.
+/// Series read before either are held as JSON text until both are known. SDMX-JSON 1.0
+/// messages usually have their data sets before the structure, so every series is held;
+/// request SDMX-JSON 2.0 with `ProtocolVersion::Sdmx30` to stream large responses. An
+/// error from `on_series` stops reading and is returned.
+pub fn read_series<R, F>(reader: R, on_series: F) -> Result<()>
+where
+    R: Read,
+    F: FnMut(TimeSeries) -> Result<()>,
+{
+    let mut state = SeriesReader {
+        on_series,
+        version: None,
+        structures: Vec::new(),
+        pending: Vec::new(),
+        structure: None,
+        unresolved: Vec::new(),
+        error: None,
+    };
+
+    let mut deserializer = serde_json::Deserializer::from_reader(BufReader::new(reader));
+    let result = MessageSeed(&mut state)
+        .deserialize(&mut deserializer)
+        .and_then(|_| deserializer.end());
+
+    match (result, state.error) {
+        (_, Some(error)) => Err(error),
+        (Err(e), None) => Err(e.into()),
+        (Ok(()), None) => Ok(()),
+    }
+}
@@ -0,0 +1,211 @@
+use quick_xml::{events::Event, Reader};
+
+use super::{attribute, attributes};
+use crate::{
+    config::Config,
+    error_code::ErrorCode,
+    models::derived::{data_sets::DataSets, sdmx_response::SdmxResponse},
+    parsers::raw_data::{self, RawMessage, RawObservation, RawSeries},
+    result::Result,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MessageKind {
+    StructureSpecific,
+    Generic,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    None,
+    SeriesKey,
+    SeriesAttributes,
+    ObservationAttributes,
+}
+
+fn read(bytes: &[u8]) -> Result<(MessageKind, RawMessage)> {
+    let mut reader = Reader::from_reader(bytes);
+    reader.trim_text(true);
+
+    let mut kind = None;
+    let mut message = RawMessage::default();
+    let mut in_header = false;
+    let mut in_observation = false;
+    let mut section = Section::None;
+    let mut text_element = Vec::new();
+
+    loop {
+        let event = reader.read_event()?;
+
+        let (element, is_start) = match &event {
+            Event::Start(element) => (element, true),
+            Event::Empty(element) => (element, false),
+            Event::End(element) => {
+                match element.local_name().as_ref() {
+                    b"Header" => in_header = false,
+                    b"Obs" => in_observation = false,
+                    b"SeriesKey" | b"Attributes" => section = Section::None,
+                    _ => (),
+                }
+                text_element.clear();
+                continue;
+            }
+            Event::Text(text) if in_header => {
+                let text = text.unescape()?;
+                match text_element.as_slice() {
+                    b"ID" => message.header.id = text.as_ref().into(),
+                    b"Test" => message.header.test = text.as_ref() == "true",
+                    b"Prepared" => message.header.prepared = text.as_ref().into(),
+                    _ => (),
+                }
+                continue;
+            }
+            Event::Eof => break,
+            _ => continue,
+        };
+
+        let name = element.local_name();
+
+        let Some(kind) = kind else {
+            kind = Some(match name.as_ref() {
+                b"StructureSpecificData" | b"StructureSpecificTimeSeriesData" => {
+                    MessageKind::StructureSpecific
+                }
+                b"GenericData" | b"GenericTimeSeriesData" => MessageKind::Generic,
+                other => {
+                    return Err(ErrorCode::UnsupportedDataMessage(
+                        String::from_utf8_lossy(other).into(),
+                    ))
+                }
+            });
+            continue;
+        };
+
+        if is_start {
+            text_element = name.as_ref().to_vec();
+        }
+
+        match name.as_ref() {
+            b"Header" => in_header = is_start,
+            b"Sender" if in_header => {
+                message.header.sender = attribute(element, "id")?.unwrap_or_default();
+            }
+            b"Structure" if in_header => {
+                if let Some(structure_id) = attribute(element, "structureID")? {
+                    message.header.structure_id = structure_id;
+                }
+                if let Some(dimension) = attribute(element, "dimensionAtObservation")? {
+                    message.header.dimension_at_observation = Some(dimension);
+                }
+            }
+            b"DataSet" => {
+                message.action = attribute(element, "action")?.unwrap_or_default();
+            }
+            b"Series" => {
+                let mut series = RawSeries::default();
+                if kind == MessageKind::StructureSpecific {
+                    series.key = attributes(element)?;
+                }
+                message.series.push(series);
+            }
+            b"Obs" => {
+                in_observation = is_start;
+                let mut observation = RawObservation::default();
+
+                if kind == MessageKind::StructureSpecific {
+                    let time_dimension = message
+                        .header
+                        .dimension_at_observation
+                        .as_deref()
+                        .unwrap_or(Config::TIME_PERIOD_DIMENSION);
+
+                    for (key, value) in attributes(element)? {
+                        if key.as_ref() == time_dimension {
+                            observation.time = value;
+                        } else if key.as_ref() == Config::PRIMARY_MEASURE {
+                            observation.value = Some(value);
+                        } else {
+                            observation.attributes.push((key, value));
+                        }
+                    }
+                }
+
+                message
+                    .series
+                    .last_mut()
+                    .ok_or(ErrorCode::UnsupportedDataMessage(
+                        "observation outside of a series".into(),
+                    ))?
+                    .observations
+                    .push(observation);
+            }
+            b"SeriesKey" => section = Section::SeriesKey,
+            b"Attributes" if in_observation => section = Section::ObservationAttributes,
+            b"Attributes" => section = Section::SeriesAttributes,
+            b"Value" | b"ObsDimension" | b"ObsValue" => {
+                let Some(series) = message.series.last_mut() else {
+                    continue;
+                };
+                let value = attribute(element, "value")?.unwrap_or_default();
+
+                match (name.as_ref(), section) {
+                    (b"ObsDimension", _) => {
+                        if let Some(observation) = series.observations.last_mut() {
+                            observation.time = value;
+                        }
+                    }
+                    (b"ObsValue", _) => {
+                        if let Some(observation) = series.observations.last_mut() {
+                            observation.value = Some(value);
+                        }
+                    }
+                    (_, Section::SeriesKey) => {
+                        let id = attribute(element, "id")?.unwrap_or_default();
+                        series.key.push((id, value));
+                    }
+                    (_, Section::SeriesAttributes) => {
+                        let id = attribute(element, "id")?.unwrap_or_default();
+                        series.attributes.push((id, value));
+                    }
+                    (_, Section::ObservationAttributes) => {
+                        let id = attribute(element, "id")?.unwrap_or_default();
+                        if let Some(observation) = series.observations.last_mut() {
+                            observation.attributes.push((id, value));
+                        }
+                    }
+                    (_, Section::None) => (),
+                }
+            }
+            _ => (),
+        }
+    }
+
+    let kind = kind.ok_or(ErrorCode::UnsupportedDataMessage("no root element".into()))?;
+
+    Ok((kind, message))
+}
+
+/// Parses an SDMX-ML 2.1 structure specific or generic data message into the same
+/// model as the SDMX-JSON messages.
+///
+/// Structure specific messages do not say which series attributes are dimensions, so
+/// any attribute not in `dimensions` is read as a series attribute. Without `dimensions`
+/// every series attribute is read as a dimension. Generic messages ignore `dimensions`.
+pub fn parse_data(bytes: &[u8], dimensions: Option<&[Box<str>]>) -> Result<SdmxResponse<DataSets>> {
+    let (kind, mut message) = read(bytes)?;
+
+    if let (MessageKind::StructureSpecific, Some(dimensions)) = (kind, dimensions) {
+        for series in &mut message.series {
+            let (key, attributes) = std::mem::take(&mut series.key)
+                .into_iter()
+                .partition(|(id, _)| dimensions.contains(id));
+            series.key = key;
+            series.attributes = attributes;
+            series
+                .key
+                .sort_by_key(|(id, _)| dimensions.iter().position(|dimension| dimension == id));
+        }
+    }
+
+    raw_data::into_response(message)
+}
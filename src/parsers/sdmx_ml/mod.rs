@@ -0,0 +1,27 @@
+use quick_xml::events::BytesStart;
+
+use crate::result::Result;
+
+mod data;
+mod structure;
+
+pub use data::parse_data;
+pub use structure::parse_structure;
+
+fn attributes(element: &BytesStart) -> Result<Vec<(Box<str>, Box<str>)>> {
+    element
+        .attributes()
+        .map(|attribute| {
+            let attribute = attribute.map_err(quick_xml::Error::from)?;
+            let key = std::str::from_utf8(attribute.key.local_name().into_inner())?;
+            Ok((key.into(), attribute.unescape_value()?.as_ref().into()))
+        })
+        .collect()
+}
+
+fn attribute(element: &BytesStart, key: &str) -> Result<Option<Box<str>>> {
+    Ok(attributes(element)?
+        .into_iter()
+        .find(|(k, _)| k.as_ref() == key)
+        .map(|(_, value)| value))
+}
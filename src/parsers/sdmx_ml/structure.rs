@@ -0,0 +1,530 @@
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
+
+use super::attributes;
+use crate::{
+    config::Config,
+    error_code::ErrorCode,
+    models::{
+        derived::{
+            annotation::Annotation,
+            category::Category,
+            code::Code,
+            component::{AttributeRelationship, Component, LocalRepresentation, TextFormat},
+            concept::Concept,
+            constraint_attachment::ConstraintAttachment,
+            cube_region::CubeRegion,
+            data_structure_components::{
+                AttributeList, DataStructureComponents, DimensionList, MeasureList,
+            },
+            descriptions::Descriptions,
+            key_value::KeyValue,
+            meta::Meta,
+            meta_data_map::MetaDataMap,
+            meta_data_sets::MetaDataSet,
+            names::Names,
+            period::Period,
+            sdmx_response::SdmxResponse,
+            sender::Sender,
+            texts::Texts,
+            time_range::TimeRange,
+        },
+        typed::version::Version,
+    },
+    result::Result,
+};
+
+const URN_PREFIX: &str = "urn:sdmx:org.sdmx.infomodel";
+const DEFAULT_VERSION: &str = "1.0";
+const DEFAULT_LANGUAGE: &str = "en";
+
+/// An element of the message with its namespace prefixes removed.
+#[derive(Default)]
+struct Element {
+    name: Box<str>,
+    attributes: Vec<(Box<str>, Box<str>)>,
+    text: Box<str>,
+    children: Vec<Element>,
+}
+
+impl Element {
+    fn new(start: &BytesStart) -> Result<Self> {
+        Ok(Self {
+            name: std::str::from_utf8(start.local_name().as_ref())?.into(),
+            attributes: attributes(start)?,
+            ..Default::default()
+        })
+    }
+
+    fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| k.as_ref() == key)
+            .map(|(_, value)| value.as_ref())
+    }
+
+    fn child(&self, name: &str) -> Option<&Element> {
+        self.children
+            .iter()
+            .find(|child| child.name.as_ref() == name)
+    }
+
+    fn children<'e>(&'e self, name: &'e str) -> impl Iterator<Item = &'e Element> {
+        self.children
+            .iter()
+            .filter(move |child| child.name.as_ref() == name)
+    }
+
+    fn text_of(&self, name: &str) -> Option<Box<str>> {
+        self.child(name).map(|child| child.text.clone())
+    }
+
+    /// The text of the localised children called `name`, preferring English.
+    fn localised(&self, name: &str) -> Option<Box<str>> {
+        self.children(name)
+            .find(|child| child.attribute("lang") == Some(DEFAULT_LANGUAGE))
+            .or_else(|| self.child(name))
+            .map(|child| child.text.clone())
+    }
+}
+
+fn read_tree(bytes: &[u8]) -> Result<Element> {
+    let mut reader = Reader::from_reader(bytes);
+    reader.trim_text(true);
+
+    let mut stack = Vec::<Element>::new();
+
+    loop {
+        let element = match reader.read_event()? {
+            Event::Start(start) => {
+                stack.push(Element::new(&start)?);
+                continue;
+            }
+            Event::Empty(start) => Element::new(&start)?,
+            Event::Text(text) => {
+                if let Some(element) = stack.last_mut() {
+                    element.text = text.unescape()?.as_ref().into();
+                }
+                continue;
+            }
+            Event::End(_) => stack.pop().ok_or(ErrorCode::UnsupportedStructureMessage(
+                "unbalanced end tag".into(),
+            ))?,
+            Event::Eof => {
+                return Err(ErrorCode::UnsupportedStructureMessage(
+                    "no root element".into(),
+                ))
+            }
+            _ => continue,
+        };
+
+        match stack.last_mut() {
+            Some(parent) => parent.children.push(element),
+            None => return Ok(element),
+        }
+    }
+}
+
+/// The urn of a `Ref` or `URN` child, using `package` and `class` when the `Ref` omits them.
+fn reference(element: &Element, package: &str, class: &str) -> Option<Box<str>> {
+    if let Some(urn) = element.text_of("URN") {
+        return Some(urn);
+    }
+
+    let reference = element.child("Ref")?;
+    let id = reference.attribute("id")?;
+    let package = reference.attribute("package").unwrap_or(package);
+    let class = reference.attribute("class").unwrap_or(class);
+    let agency_id = reference
+        .attribute("agencyID")
+        .unwrap_or(Config::DEFAULT_AGENCY_ID);
+
+    let urn = match reference.attribute("maintainableParentID") {
+        Some(parent_id) => format!(
+            "{}.{}.{}={}:{}({}).{}",
+            URN_PREFIX,
+            package,
+            class,
+            agency_id,
+            parent_id,
+            reference
+                .attribute("maintainableParentVersion")
+                .unwrap_or(DEFAULT_VERSION),
+            id
+        ),
+        None => format!(
+            "{}.{}.{}={}:{}({})",
+            URN_PREFIX,
+            package,
+            class,
+            agency_id,
+            id,
+            reference.attribute("version").unwrap_or(DEFAULT_VERSION)
+        ),
+    };
+
+    Some(urn.into())
+}
+
+fn reference_id(element: &Element) -> Option<Box<str>> {
+    element
+        .child("Ref")
+        .and_then(|reference| reference.attribute("id"))
+        .map(Into::into)
+}
+
+fn names(element: &Element) -> (Box<str>, Names) {
+    let name = element.localised("Name").unwrap_or_default();
+    (name.clone(), Names { en: name })
+}
+
+fn descriptions(element: &Element) -> (Option<Box<str>>, Option<Descriptions>) {
+    let description = element.localised("Description");
+    let descriptions = description.clone().map(|en| Descriptions { en });
+    (description, descriptions)
+}
+
+fn annotations(element: &Element) -> Option<Vec<Annotation>> {
+    let annotations = element.child("Annotations")?;
+
+    Some(
+        annotations
+            .children("Annotation")
+            .map(|annotation| {
+                let text = annotation.localised("AnnotationText");
+                Annotation {
+                    _type: annotation.text_of("AnnotationType"),
+                    texts: text.clone().map(|en| Texts { en }),
+                    text,
+                    title: annotation.text_of("AnnotationTitle"),
+                    id: annotation.attribute("id").map(Into::into),
+                }
+            })
+            .collect(),
+    )
+}
+
+fn flag(element: &Element, key: &str) -> Option<bool> {
+    element.attribute(key).map(|value| value == "true")
+}
+
+fn maintainable(element: &Element) -> Result<MetaDataSet> {
+    let (name, names) = names(element);
+    let (description, descriptions) = descriptions(element);
+
+    let version = match element.attribute("version") {
+        Some(version) => Version::try_from(Box::<str>::from(version))?,
+        None => Version::default(),
+    };
+
+    Ok(MetaDataSet {
+        id: element.attribute("id").unwrap_or_default().into(),
+        version,
+        agency_id: element
+            .attribute("agencyID")
+            .unwrap_or(Config::DEFAULT_AGENCY_ID)
+            .into(),
+        is_external_reference: flag(element, "isExternalReference"),
+        is_final: flag(element, "isFinal").unwrap_or_default(),
+        is_partial: flag(element, "isPartial"),
+        name,
+        names,
+        description,
+        descriptions,
+        annotations: annotations(element).map(Into::into),
+        ..Default::default()
+    })
+}
+
+fn code(element: &Element) -> Code {
+    let (name, names) = names(element);
+    let (description, descriptions) = descriptions(element);
+
+    Code {
+        id: element.attribute("id").unwrap_or_default().into(),
+        name,
+        names,
+        description,
+        descriptions,
+        annotations: annotations(element).unwrap_or_default(),
+        parent: element.child("Parent").and_then(reference_id),
+    }
+}
+
+fn concept(element: &Element) -> Concept {
+    let Code {
+        id,
+        name,
+        names,
+        description,
+        descriptions,
+        annotations,
+        parent,
+    } = code(element);
+
+    Concept {
+        id,
+        name,
+        names,
+        description,
+        descriptions,
+        annotations,
+        parent,
+    }
+}
+
+fn category(element: &Element) -> Category {
+    let (name, names) = names(element);
+    let categories = element
+        .children("Category")
+        .map(category)
+        .collect::<Box<_>>();
+
+    Category {
+        id: element.attribute("id").unwrap_or_default().into(),
+        name,
+        names,
+        categories: (!categories.is_empty()).then_some(categories),
+    }
+}
+
+fn period(element: &Element) -> Period {
+    Period {
+        period: element.text.clone(),
+        is_inclusive: flag(element, "isInclusive").unwrap_or(true),
+    }
+}
+
+fn key_value(element: &Element) -> KeyValue {
+    KeyValue {
+        id: element.attribute("id").unwrap_or_default().into(),
+        values: element
+            .children("Value")
+            .map(|value| value.text.clone())
+            .collect(),
+        time_range: element.child("TimeRange").map(|time_range| TimeRange {
+            start_period: time_range
+                .child("StartPeriod")
+                .or_else(|| time_range.child("AfterPeriod"))
+                .map(period)
+                .unwrap_or_default(),
+            end_period: time_range
+                .child("EndPeriod")
+                .or_else(|| time_range.child("BeforePeriod"))
+                .map(period)
+                .unwrap_or_default(),
+        }),
+    }
+}
+
+fn component(element: &Element, _type: Option<&str>) -> Component {
+    Component {
+        id: element.attribute("id").unwrap_or_default().into(),
+        position: element
+            .attribute("position")
+            .and_then(|position| position.parse::<usize>().ok())
+            .map(|position| position.saturating_sub(1)),
+        _type: _type.map(Into::into),
+        concept_identity: element
+            .child("ConceptIdentity")
+            .and_then(|concept| reference(concept, "conceptscheme", "Concept")),
+        local_representation: element.child("LocalRepresentation").map(|representation| {
+            LocalRepresentation {
+                enumeration: representation
+                    .child("Enumeration")
+                    .and_then(|enumeration| reference(enumeration, "codelist", "Codelist")),
+                text_format: representation
+                    .child("TextFormat")
+                    .map(|text_format| TextFormat {
+                        text_type: text_format.attribute("textType").map(Into::into),
+                    }),
+            }
+        }),
+        assignment_status: element.attribute("assignmentStatus").map(Into::into),
+        attribute_relationship: element.child("AttributeRelationship").map(|relationship| {
+            let dimensions = relationship
+                .children("Dimension")
+                .filter_map(reference_id)
+                .collect::<Box<[_]>>();
+
+            AttributeRelationship {
+                dimensions: (!dimensions.is_empty()).then_some(dimensions),
+                primary_measure: relationship.child("PrimaryMeasure").and_then(reference_id),
+            }
+        }),
+    }
+}
+
+fn data_structure_components(element: &Element) -> DataStructureComponents {
+    let dimension_list = element.child("DimensionList");
+
+    DataStructureComponents {
+        attribute_list: element.child("AttributeList").map(|list| AttributeList {
+            id: list.attribute("id").unwrap_or_default().into(),
+            attributes: list
+                .children("Attribute")
+                .map(|attribute| component(attribute, None))
+                .collect(),
+        }),
+        dimension_list: DimensionList {
+            id: dimension_list
+                .and_then(|list| list.attribute("id"))
+                .unwrap_or_default()
+                .into(),
+            dimensions: dimension_list
+                .into_iter()
+                .flat_map(|list| list.children.iter())
+                .filter(|child| matches!(child.name.as_ref(), "Dimension" | "MeasureDimension"))
+                .map(|dimension| component(dimension, Some(&dimension.name)))
+                .collect(),
+            time_dimensions: dimension_list
+                .into_iter()
+                .flat_map(|list| list.children("TimeDimension"))
+                .map(|dimension| component(dimension, Some(&dimension.name)))
+                .collect(),
+        },
+        measure_list: element.child("MeasureList").and_then(|list| {
+            Some(MeasureList {
+                id: list.attribute("id").unwrap_or_default().into(),
+                primary_measure: component(list.child("PrimaryMeasure")?, None),
+            })
+        }),
+    }
+}
+
+fn dataflow(element: &Element) -> Result<MetaDataSet> {
+    Ok(MetaDataSet {
+        structure: element
+            .child("Structure")
+            .and_then(|structure| reference(structure, "datastructure", "DataStructure")),
+        ..maintainable(element)?
+    })
+}
+
+fn data_structure(element: &Element) -> Result<MetaDataSet> {
+    Ok(MetaDataSet {
+        data_structure_components: element
+            .child("DataStructureComponents")
+            .map(data_structure_components),
+        ..maintainable(element)?
+    })
+}
+
+fn codelist(element: &Element) -> Result<MetaDataSet> {
+    Ok(MetaDataSet {
+        codes: Some(element.children("Code").map(code).collect()),
+        ..maintainable(element)?
+    })
+}
+
+fn concept_scheme(element: &Element) -> Result<MetaDataSet> {
+    Ok(MetaDataSet {
+        concepts: Some(element.children("Concept").map(concept).collect()),
+        ..maintainable(element)?
+    })
+}
+
+fn category_scheme(element: &Element) -> Result<MetaDataSet> {
+    Ok(MetaDataSet {
+        categories: Some(element.children("Category").map(category).collect()),
+        ..maintainable(element)?
+    })
+}
+
+fn constraint(element: &Element) -> Result<MetaDataSet> {
+    Ok(MetaDataSet {
+        _type: element.attribute("type").map(Into::into),
+        constraint_attachment: element.child("ConstraintAttachment").map(|attachment| {
+            ConstraintAttachment {
+                dataflows: attachment
+                    .children("Dataflow")
+                    .filter_map(|dataflow| reference(dataflow, "datastructure", "Dataflow"))
+                    .collect(),
+            }
+        }),
+        cube_regions: Some(
+            element
+                .children("CubeRegion")
+                .map(|region| CubeRegion {
+                    is_included: flag(region, "include").unwrap_or(true),
+                    key_values: region.children("KeyValue").map(key_value).collect(),
+                })
+                .collect(),
+        ),
+        ..maintainable(element)?
+    })
+}
+
+/// Parses the artefacts named `item` inside the `container` of `structures`, or `None`
+/// when the message has no such container.
+fn artefacts(
+    structures: Option<&Element>,
+    container: &str,
+    item: &str,
+    parse: fn(&Element) -> Result<MetaDataSet>,
+) -> Result<Option<Box<[MetaDataSet]>>> {
+    structures
+        .and_then(|structures| structures.child(container))
+        .map(|container| container.children(item).map(parse).collect())
+        .transpose()
+}
+
+fn meta(header: Option<&Element>) -> Meta {
+    let Some(header) = header else {
+        return Meta::default();
+    };
+
+    Meta {
+        id: header.text_of("ID").unwrap_or_default(),
+        test: header.text_of("Test").as_deref() == Some("true"),
+        prepared: header.text_of("Prepared").unwrap_or_default(),
+        sender: Sender {
+            id: header
+                .child("Sender")
+                .and_then(|sender| sender.attribute("id"))
+                .unwrap_or_default()
+                .into(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Parses an SDMX-ML 2.1 structure message into the same model as SDMX-JSON structure
+/// messages. References are converted to urns and component positions are zero based.
+pub fn parse_structure(bytes: &[u8]) -> Result<SdmxResponse<MetaDataMap>> {
+    let root = read_tree(bytes)?;
+
+    if root.name.as_ref() != "Structure" {
+        return Err(ErrorCode::UnsupportedStructureMessage(root.name));
+    }
+
+    let structures = root.child("Structures");
+
+    let data = MetaDataMap {
+        dataflows: artefacts(structures, "Dataflows", "Dataflow", dataflow)?,
+        content_constraints: artefacts(structures, "Constraints", "ContentConstraint", constraint)?,
+        data_structures: artefacts(
+            structures,
+            "DataStructures",
+            "DataStructure",
+            data_structure,
+        )?,
+        codelists: artefacts(structures, "Codelists", "Codelist", codelist)?,
+        concept_schemes: artefacts(structures, "Concepts", "ConceptScheme", concept_scheme)?,
+        category_schemes: artefacts(
+            structures,
+            "CategorySchemes",
+            "CategoryScheme",
+            category_scheme,
+        )?,
+    };
+
+    Ok(SdmxResponse {
+        data,
+        meta: meta(root.child("Header")),
+        structure: None,
+    })
+}
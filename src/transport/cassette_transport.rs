@@ -0,0 +1,223 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use crate::{config::Config, error_code::ErrorCode, result::Result};
+
+use super::{Transport, TransportResponse};
+
+const REDACTED: &str = "[REDACTED]";
+
+/// A recorded set of request and response pairs, stored on disk as pretty printed JSON
+/// so it can be reviewed in diffs.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub url: Box<str>,
+    pub headers: BTreeMap<Box<str>, Box<str>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: BTreeMap<Box<str>, Box<str>>,
+    pub body: Box<str>,
+}
+
+impl Cassette {
+    pub fn load<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let bytes = fs::read(path)?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    pub fn save<P>(&self, path: P) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+}
+
+impl RecordedRequest {
+    fn new(url: &str, headers: &[(&str, &str)]) -> Self {
+        let headers = headers
+            .iter()
+            .map(|(key, value)| {
+                let value = if key.eq_ignore_ascii_case(Config::HEADER_API_KEY) {
+                    REDACTED
+                } else {
+                    value
+                };
+                (key.to_ascii_lowercase().into(), (*value).into())
+            })
+            .collect();
+
+        Self {
+            url: url.into(),
+            headers,
+        }
+    }
+
+    fn accept(&self) -> Option<&str> {
+        self.headers
+            .get(Config::HEADER_ACCEPT_KEY.to_ascii_lowercase().as_str())
+            .map(AsRef::as_ref)
+    }
+
+    /// Requests match on url and `Accept` header; other headers such as the api key or
+    /// user agent may change between recording and replay.
+    fn matches(&self, other: &Self) -> bool {
+        self.url == other.url && self.accept() == other.accept()
+    }
+}
+
+#[derive(Debug)]
+enum Mode {
+    Record {
+        inner: Box<dyn Transport>,
+        cassette: Mutex<Cassette>,
+    },
+    Replay {
+        cassette: Cassette,
+        served: Mutex<Vec<bool>>,
+    },
+}
+
+/// Records every request made through an inner `Transport` to a cassette file, or
+/// replays a previously recorded cassette without touching the network.
+#[derive(Debug)]
+pub struct CassetteTransport {
+    path: PathBuf,
+    mode: Mode,
+}
+
+impl CassetteTransport {
+    /// Sends requests through `inner` and writes each interaction to `path`, replacing
+    /// any cassette already there.
+    pub fn record<P, T>(path: P, inner: T) -> Self
+    where
+        P: Into<PathBuf>,
+        T: Transport + 'static,
+    {
+        Self {
+            path: path.into(),
+            mode: Mode::Record {
+                inner: Box::new(inner),
+                cassette: Mutex::new(Cassette::default()),
+            },
+        }
+    }
+
+    /// Serves the interactions recorded at `path`. Repeated requests are answered in
+    /// recorded order, then with the last match; unknown requests are an error.
+    pub fn replay<P>(path: P) -> Result<Self>
+    where
+        P: Into<PathBuf>,
+    {
+        let path = path.into();
+        let cassette = Cassette::load(&path)?;
+        let served = Mutex::new(vec![false; cassette.interactions.len()]);
+
+        Ok(Self {
+            path,
+            mode: Mode::Replay { cassette, served },
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn replay_response(
+        cassette: &Cassette,
+        served: &Mutex<Vec<bool>>,
+        request: &RecordedRequest,
+    ) -> Result<TransportResponse> {
+        let mut served = served.lock().expect("cassette lock poisoned");
+
+        let matching = cassette
+            .interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, interaction)| interaction.request.matches(request))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        let index = matching
+            .iter()
+            .copied()
+            .find(|i| !served[*i])
+            .or(matching.last().copied())
+            .ok_or_else(|| ErrorCode::CassetteInteractionNotFound(request.url.clone()))?;
+
+        served[index] = true;
+
+        let response = &cassette.interactions[index].response;
+        let status = StatusCode::from_u16(response.status)
+            .map_err(|e| ErrorCode::Custom(e.to_string().into()))?;
+
+        let headers = response
+            .headers
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+
+        Ok(TransportResponse::new(status, response.body.as_bytes().into()).with_headers(headers))
+    }
+}
+
+#[async_trait]
+impl Transport for CassetteTransport {
+    async fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<TransportResponse> {
+        let request = RecordedRequest::new(url, headers);
+
+        match &self.mode {
+            Mode::Record { inner, cassette } => {
+                let response = inner.get(url, headers).await?;
+
+                let interaction = Interaction {
+                    request,
+                    response: RecordedResponse {
+                        status: response.status.as_u16(),
+                        headers: response
+                            .headers
+                            .iter()
+                            .map(|(key, value)| (key.to_ascii_lowercase().into(), value.clone()))
+                            .collect(),
+                        body: std::str::from_utf8(&response.body)?.into(),
+                    },
+                };
+
+                let mut cassette = cassette.lock().expect("cassette lock poisoned");
+                cassette.interactions.push(interaction);
+                cassette.save(&self.path)?;
+
+                Ok(response)
+            }
+            Mode::Replay { cassette, served } => Self::replay_response(cassette, served, &request),
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{config::Config, result::Result};
+
+use super::TransportResponse;
+
+/// Keeps the temporary files of concurrent writes within a process apart.
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// A cached response body along with the validators needed to revalidate it.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheEntry {
+    pub url: Box<str>,
+    pub accept: Option<Box<str>>,
+    pub etag: Option<Box<str>>,
+    pub last_modified: Option<Box<str>>,
+    /// Seconds since the unix epoch at which the entry was stored or last revalidated.
+    pub validated_at: u64,
+    pub body: Box<str>,
+}
+
+impl CacheEntry {
+    fn age(&self) -> Duration {
+        let validated_at = UNIX_EPOCH + Duration::from_secs(self.validated_at);
+
+        SystemTime::now()
+            .duration_since(validated_at)
+            .unwrap_or(Duration::ZERO)
+    }
+
+    /// Headers that turn a request for this entry into a conditional request.
+    pub fn conditional_headers(&self) -> Vec<(&'static str, &str)> {
+        let mut headers = Vec::new();
+
+        if let Some(etag) = &self.etag {
+            headers.push((Config::HEADER_IF_NONE_MATCH_KEY, etag.as_ref()));
+        }
+        if let Some(last_modified) = &self.last_modified {
+            headers.push((Config::HEADER_IF_MODIFIED_SINCE_KEY, last_modified.as_ref()));
+        }
+
+        headers
+    }
+}
+
+/// A persistent cache of successful responses keyed by url and `Accept` header.
+/// Entries younger than `max_age` are served without a request; older entries are
+/// revalidated with `If-None-Match` / `If-Modified-Since`.
+#[derive(Debug, Clone)]
+pub struct HttpCache {
+    dir: PathBuf,
+    max_age: Duration,
+}
+
+impl HttpCache {
+    pub fn new<P>(dir: P, max_age: Duration) -> Result<Self>
+    where
+        P: Into<PathBuf>,
+    {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+
+        Ok(Self { dir, max_age })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn max_age(&self) -> Duration {
+        self.max_age
+    }
+
+    /// A stable FNV-1a hash, so file names survive toolchain upgrades.
+    fn hash(value: &str) -> u64 {
+        value.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        })
+    }
+
+    fn url_prefix(url: &str) -> String {
+        format!("{:016x}-", Self::hash(url))
+    }
+
+    fn path(&self, url: &str, accept: Option<&str>) -> PathBuf {
+        let name = format!(
+            "{}{:016x}.json",
+            Self::url_prefix(url),
+            Self::hash(accept.unwrap_or_default())
+        );
+        self.dir.join(name)
+    }
+
+    /// Returns the entry for `url` and `accept`. Entries that cannot be read or parsed,
+    /// such as those left truncated by an interrupted write, are removed and treated as
+    /// a miss.
+    pub fn get(&self, url: &str, accept: Option<&str>) -> Result<Option<CacheEntry>> {
+        let path = self.path(url, accept);
+
+        let entry = match fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice::<CacheEntry>(&bytes).ok(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(_) => None,
+        };
+
+        let Some(entry) = entry else {
+            let _ = fs::remove_file(&path);
+            return Ok(None);
+        };
+
+        if entry.url.as_ref() != url || entry.accept.as_deref() != accept {
+            return Ok(None);
+        }
+
+        Ok(Some(entry))
+    }
+
+    pub fn is_fresh(&self, entry: &CacheEntry) -> bool {
+        entry.age() < self.max_age
+    }
+
+    /// Writes `entry` to a temporary file in the cache directory and renames it into
+    /// place, so that readers never see a partially written entry.
+    pub fn put(&self, entry: &CacheEntry) -> Result<()> {
+        let json = serde_json::to_string(entry)?;
+        let path = self.path(&entry.url, entry.accept.as_deref());
+        let temp = path.with_extension(format!(
+            "{}-{}.tmp",
+            std::process::id(),
+            TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        fs::write(&temp, json)?;
+
+        if let Err(e) = fs::rename(&temp, &path) {
+            let _ = fs::remove_file(&temp);
+            return Err(e.into());
+        }
+
+        Ok(())
+    }
+
+    /// Stores a successful response, returning `None` when the body is not UTF-8.
+    pub fn store(
+        &self,
+        url: &str,
+        accept: Option<&str>,
+        response: &TransportResponse,
+    ) -> Result<Option<CacheEntry>> {
+        let Ok(body) = std::str::from_utf8(&response.body) else {
+            return Ok(None);
+        };
+
+        let entry = CacheEntry {
+            url: url.into(),
+            accept: accept.map(Into::into),
+            etag: response.header(Config::HEADER_ETAG_KEY).map(Into::into),
+            last_modified: response
+                .header(Config::HEADER_LAST_MODIFIED_KEY)
+                .map(Into::into),
+            validated_at: now(),
+            body: body.into(),
+        };
+
+        self.put(&entry)?;
+        Ok(Some(entry))
+    }
+
+    /// Marks an entry as just validated after a `304 Not Modified`.
+    pub fn touch(&self, entry: &mut CacheEntry) -> Result<()> {
+        entry.validated_at = now();
+        self.put(entry)
+    }
+
+    /// Removes every cached representation of `url`.
+    pub fn invalidate(&self, url: &str) -> Result<()> {
+        let prefix = Self::url_prefix(url);
+
+        for file in fs::read_dir(&self.dir)? {
+            let file = file?;
+            if file.file_name().to_string_lossy().starts_with(&prefix) {
+                fs::remove_file(file.path())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn clear(&self) -> Result<()> {
+        for file in fs::read_dir(&self.dir)? {
+            let path = file?.path();
+            if path.extension().is_some_and(|e| e == "json") {
+                fs::remove_file(path)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+        .as_secs()
+}
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use reqwest::StatusCode;
+
+use crate::result::Result;
+
+use super::{Transport, TransportResponse};
+
+/// Serves canned responses keyed by the full request url, without touching the network.
+/// Requests for urls that have not been routed receive a `404 Not Found`.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryTransport {
+    routes: HashMap<Box<str>, TransportResponse>,
+}
+
+impl MemoryTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn route<B>(self, url: &str, body: B) -> Self
+    where
+        B: Into<Box<[u8]>>,
+    {
+        self.route_with_status(url, StatusCode::OK, body)
+    }
+
+    pub fn route_with_status<B>(mut self, url: &str, status: StatusCode, body: B) -> Self
+    where
+        B: Into<Box<[u8]>>,
+    {
+        self.routes
+            .insert(url.into(), TransportResponse::new(status, body.into()));
+        self
+    }
+}
+
+#[async_trait]
+impl Transport for MemoryTransport {
+    async fn get(&self, url: &str, _headers: &[(&str, &str)]) -> Result<TransportResponse> {
+        Ok(self
+            .routes
+            .get(url)
+            .cloned()
+            .unwrap_or_else(|| TransportResponse::new(StatusCode::NOT_FOUND, Box::new([]))))
+    }
+}
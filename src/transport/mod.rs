@@ -0,0 +1,108 @@
+pub mod cassette_transport;
+pub mod http_cache;
+pub mod memory_transport;
+pub mod reqwest_transport;
+
+use std::borrow::Borrow;
+
+use async_trait::async_trait;
+
+use crate::result::Result;
+
+/// The raw outcome of a request made through a `Transport`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransportResponse {
+    pub status: reqwest::StatusCode,
+    pub headers: Box<[(Box<str>, Box<str>)]>,
+    pub body: Box<[u8]>,
+}
+
+impl TransportResponse {
+    pub fn new(status: reqwest::StatusCode, body: Box<[u8]>) -> Self {
+        Self {
+            status,
+            headers: Box::new([]),
+            body,
+        }
+    }
+
+    pub fn with_headers(mut self, headers: Box<[(Box<str>, Box<str>)]>) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// Looks up a response header, ignoring the case of the key.
+    pub fn header(&self, key: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, v)| v.as_ref())
+    }
+}
+
+/// A response body read a chunk at a time, as it arrives.
+#[async_trait]
+pub trait BodyStream: Send {
+    /// The next chunk of the body, or `None` once it has been read in full.
+    async fn chunk(&mut self) -> Result<Option<Box<[u8]>>>;
+}
+
+/// A body already held in memory, served as a single chunk.
+#[derive(Debug, Clone, Default)]
+pub struct BufferedBody {
+    body: Option<Box<[u8]>>,
+}
+
+impl BufferedBody {
+    pub fn new(body: Box<[u8]>) -> Self {
+        Self { body: Some(body) }
+    }
+}
+
+#[async_trait]
+impl BodyStream for BufferedBody {
+    async fn chunk(&mut self) -> Result<Option<Box<[u8]>>> {
+        Ok(self.body.take().filter(|body| !body.is_empty()))
+    }
+}
+
+/// The outcome of a request made through `Transport::get_stream`. `head` holds the
+/// status and headers with an empty body, which is read from `body` instead.
+pub struct StreamingResponse {
+    pub head: TransportResponse,
+    pub body: Box<dyn BodyStream>,
+}
+
+impl From<TransportResponse> for StreamingResponse {
+    fn from(response: TransportResponse) -> Self {
+        let TransportResponse {
+            status,
+            headers,
+            body,
+        } = response;
+
+        Self {
+            head: TransportResponse::new(status, Box::new([])).with_headers(headers),
+            body: Box::new(BufferedBody::new(body)),
+        }
+    }
+}
+
+impl Borrow<TransportResponse> for StreamingResponse {
+    fn borrow(&self) -> &TransportResponse {
+        &self.head
+    }
+}
+
+/// Performs the HTTP GET requests made by an `SdmxClient`. Implement this to route
+/// requests somewhere other than the network, such as canned fixtures in tests.
+#[async_trait]
+pub trait Transport: std::fmt::Debug + Send + Sync {
+    async fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<TransportResponse>;
+
+    /// Sends a GET request whose body is read as it arrives. Transports that only hold
+    /// whole bodies serve them as a single chunk.
+    async fn get_stream(&self, url: &str, headers: &[(&str, &str)]) -> Result<StreamingResponse> {
+        Ok(self.get(url, headers).await?.into())
+    }
+}
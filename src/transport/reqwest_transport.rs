@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+
+use crate::result::Result;
+
+use super::{BodyStream, StreamingResponse, Transport, TransportResponse};
+
+/// Sends requests over the network with a `reqwest::Client`.
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    inner: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(inner: reqwest::Client) -> Self {
+        Self { inner }
+    }
+
+    pub fn inner(&self) -> &reqwest::Client {
+        &self.inner
+    }
+
+    async fn send(&self, url: &str, headers: &[(&str, &str)]) -> Result<reqwest::Response> {
+        let mut request = self.inner.get(url);
+
+        for (key, value) in headers {
+            request = request.header(*key, *value);
+        }
+
+        Ok(request.send().await?)
+    }
+
+    /// The status and headers of `response`, with an empty body.
+    fn head(response: &reqwest::Response) -> TransportResponse {
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(key, value)| Some((key.as_str().into(), value.to_str().ok()?.into())))
+            .collect();
+
+        TransportResponse::new(response.status(), Box::new([])).with_headers(headers)
+    }
+}
+
+/// Reads the body of a `reqwest::Response` as its chunks arrive.
+struct ReqwestBody {
+    response: reqwest::Response,
+}
+
+#[async_trait]
+impl BodyStream for ReqwestBody {
+    async fn chunk(&mut self) -> Result<Option<Box<[u8]>>> {
+        Ok(self
+            .response
+            .chunk()
+            .await?
+            .map(|chunk| chunk.to_vec().into()))
+    }
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<TransportResponse> {
+        let response = self.send(url, headers).await?;
+        let head = Self::head(&response);
+        let body = response.bytes().await?;
+
+        Ok(TransportResponse {
+            body: body.to_vec().into(),
+            ..head
+        })
+    }
+
+    async fn get_stream(&self, url: &str, headers: &[(&str, &str)]) -> Result<StreamingResponse> {
+        let response = self.send(url, headers).await?;
+
+        Ok(StreamingResponse {
+            head: Self::head(&response),
+            body: Box::new(ReqwestBody { response }),
+        })
+    }
+}
@@ -0,0 +1,108 @@
+#[cfg(all(test, feature = "arrow"))]
+mod tests {
+    use abs_data::{
+        error_code::ErrorCode, exporters::arrow::METADATA_LABELS, models::derived::value::Value,
+        parsers::sdmx_json, result::Result,
+    };
+    use arrow_array::{
+        cast::AsArray,
+        types::{Date32Type, Float64Type, Int32Type},
+        Array,
+    };
+    use arrow_schema::DataType;
+    use chrono::NaiveDate;
+
+    const CPI_DATA: &[u8] = include_bytes!("fixtures/cpi_data.json");
+
+    #[test]
+    fn data_converts_to_a_record_batch() -> Result<()> {
+        let batch = sdmx_json::parse_data(CPI_DATA)?.to_record_batch()?;
+
+        assert_eq!(batch.num_rows(), 8);
+
+        let schema = batch.schema();
+        let names = schema
+            .fields()
+            .iter()
+            .map(|field| field.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                "MEASURE",
+                "INDEX",
+                "TSEST",
+                "REGION",
+                "FREQ",
+                "TIME_PERIOD",
+                "OBS_VALUE",
+                "UNIT_MEASURE",
+                "OBS_STATUS",
+            ]
+        );
+
+        let region = schema.field_with_name("REGION")?;
+        assert_eq!(
+            region.data_type(),
+            &DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+        );
+        assert!(region.metadata()[METADATA_LABELS].contains(r#""8":"Canberra""#));
+
+        let regions = batch
+            .column_by_name("REGION")
+            .expect("REGION")
+            .as_dictionary::<Int32Type>();
+        let codes = regions.values().as_string::<i32>();
+        assert_eq!(codes.value(regions.keys().value(0) as usize), "1");
+        assert_eq!(codes.value(regions.keys().value(7) as usize), "8");
+
+        let time_periods = batch
+            .column_by_name("TIME_PERIOD")
+            .expect("TIME_PERIOD")
+            .as_primitive::<Date32Type>();
+        assert_eq!(
+            time_periods.value_as_date(1),
+            NaiveDate::from_ymd_opt(2023, 4, 1)
+        );
+
+        let values = batch
+            .column_by_name("OBS_VALUE")
+            .expect("OBS_VALUE")
+            .as_primitive::<Float64Type>();
+        assert_eq!(values.value(0), 130.8);
+
+        let statuses = batch
+            .column_by_name("OBS_STATUS")
+            .expect("OBS_STATUS")
+            .as_dictionary::<Int32Type>();
+        assert!(statuses.is_null(0));
+        assert!(statuses.is_valid(2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn codes_missing_from_the_structure_are_rejected() -> Result<()> {
+        let mut response = sdmx_json::parse_data(CPI_DATA)?;
+        let structure = response.data.structure.as_mut().expect("structure");
+
+        // An observation attribute sharing its id with a series attribute, so its column
+        // receives the series attribute's codes.
+        let mut shadow = structure.attributes.observation[0].clone();
+        shadow.id = structure.attributes.series[0].id.clone();
+        shadow.values = Box::new([Value {
+            id: "OTHER".into(),
+            ..Default::default()
+        }]);
+        structure.attributes.observation = std::iter::once(shadow)
+            .chain(structure.attributes.observation.iter().cloned())
+            .collect();
+
+        assert!(matches!(
+            response.to_record_batch(),
+            Err(ErrorCode::UnknownCode(id, _)) if id.as_ref() == "UNIT_MEASURE"
+        ));
+
+        Ok(())
+    }
+}
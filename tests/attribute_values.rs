@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use abs_data::{parsers::sdmx_json, result::Result};
+
+    const CPI_DATA: &[u8] = include_bytes!("fixtures/cpi_data.json");
+    const CPI_DATA_V2: &[u8] = include_bytes!("fixtures/cpi_data_v2.json");
+
+    #[test]
+    fn series_attributes_resolve_to_values() -> Result<()> {
+        let response = sdmx_json::parse_data(CPI_DATA)?;
+        let structure = response.data.structure.as_ref().expect("structure");
+        let series = &response.data.data_sets[0].series;
+
+        let index = structure.series_attributes(&series["0:0:0:0:0"]);
+        assert_eq!(index["UNIT_MEASURE"].id.as_ref(), "IN");
+
+        let change = structure.series_attributes(&series["1:0:0:1:0"]);
+        assert_eq!(change["UNIT_MEASURE"].id.as_ref(), "PCT");
+        assert_eq!(change.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn observation_attributes_resolve_to_values() -> Result<()> {
+        let response = sdmx_json::parse_data(CPI_DATA)?;
+        let structure = response.data.structure.as_ref().expect("structure");
+        let observations = &response.data.data_sets[0].series["0:0:0:0:0"].observations;
+
+        let estimated = structure.observation_attributes(&observations["2"]);
+        assert_eq!(estimated["OBS_STATUS"].id.as_ref(), "E");
+        assert_eq!(estimated["OBS_STATUS"].name.as_ref(), "Estimated value");
+
+        let final_figure = structure.observation_attributes(&observations["0"]);
+        assert!(final_figure.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn observation_attributes_follow_every_measure() -> Result<()> {
+        let mut message: serde_json::Value = serde_json::from_slice(CPI_DATA_V2)?;
+        let data = &mut message["data"];
+
+        let measures = data["structures"][0]["measures"]["observation"]
+            .as_array_mut()
+            .expect("measures");
+        let mut second = measures[0].clone();
+        second["id"] = "OBS_VALUE_2".into();
+        measures.push(second);
+
+        for series in data["dataSets"][0]["series"]
+            .as_object_mut()
+            .expect("series")
+            .values_mut()
+        {
+            for observation in series["observations"]
+                .as_object_mut()
+                .expect("observations")
+                .values_mut()
+            {
+                let observation = observation.as_array_mut().expect("observation");
+                observation.insert(1, 1.0.into());
+            }
+        }
+
+        let response = sdmx_json::parse_data(&serde_json::to_vec(&message)?)?;
+        let structure = response.data.structure.as_ref().expect("structure");
+        let observations = &response.data.data_sets[0].series["0:0:0:0:0"].observations;
+
+        let estimated = structure.observation_attributes(&observations["2"]);
+        assert_eq!(estimated["OBS_STATUS"].id.as_ref(), "E");
+
+        let final_figure = structure.observation_attributes(&observations["0"]);
+        assert!(final_figure.is_empty());
+
+        Ok(())
+    }
+}
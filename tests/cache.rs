@@ -0,0 +1,204 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use abs_data::{
+        builders::sdmx_client_builder::SdmxClientBuilder,
+        models::typed::{sdmx_client::SdmxClient, structure_type::StructureType},
+        result::Result,
+        transport::http_cache::HttpCache,
+    };
+    use wiremock::{
+        matchers::{header, header_exists, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const DATAFLOWS: &[u8] = include_bytes!("fixtures/dataflows.json");
+    const ETAG: &str = "\"dataflows-v1\"";
+
+    async fn server() -> MockServer {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/dataflow/ABS"))
+            .and(header("If-None-Match", ETAG))
+            .respond_with(ResponseTemplate::new(304))
+            .with_priority(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/dataflow/ABS"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", ETAG)
+                    .set_body_bytes(DATAFLOWS),
+            )
+            .with_priority(2)
+            .mount(&server)
+            .await;
+
+        server
+    }
+
+    fn client(server: &MockServer, cache: &HttpCache) -> Result<SdmxClient> {
+        SdmxClientBuilder::new()
+            .base_url(&server.uri())
+            .cache(cache.clone())
+            .build()
+    }
+
+    async fn dataflow_count(client: &SdmxClient) -> Result<usize> {
+        let response = client
+            .meta_request(&StructureType::DataFlow)
+            .build()
+            .send()
+            .await?;
+
+        Ok(response.data.dataflows.unwrap_or_default().len())
+    }
+
+    async fn received(server: &MockServer) -> Vec<wiremock::Request> {
+        server.received_requests().await.unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn fresh_entries_are_served_without_a_request() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let server = server().await;
+        let cache = HttpCache::new(dir.path(), Duration::from_secs(3600))?;
+        let client = client(&server, &cache)?;
+
+        assert_eq!(dataflow_count(&client).await?, 2);
+        assert_eq!(dataflow_count(&client).await?, 2);
+
+        assert_eq!(received(&server).await.len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn stale_entries_are_revalidated() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let server = server().await;
+        let cache = HttpCache::new(dir.path(), Duration::ZERO)?;
+        let client = client(&server, &cache)?;
+
+        assert_eq!(dataflow_count(&client).await?, 2);
+        assert_eq!(dataflow_count(&client).await?, 2);
+
+        let requests = received(&server).await;
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].headers.get("If-None-Match").is_none());
+        assert_eq!(
+            requests[1]
+                .headers
+                .get("If-None-Match")
+                .unwrap()
+                .to_str()
+                .ok(),
+            Some(ETAG)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cache_persists_across_clients() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let server = server().await;
+
+        let first = HttpCache::new(dir.path(), Duration::from_secs(3600))?;
+        dataflow_count(&client(&server, &first)?).await?;
+
+        let second = HttpCache::new(dir.path(), Duration::from_secs(3600))?;
+        dataflow_count(&client(&server, &second)?).await?;
+
+        assert_eq!(received(&server).await.len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalidated_entries_are_fetched_unconditionally() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let server = server().await;
+        let cache = HttpCache::new(dir.path(), Duration::from_secs(3600))?;
+        let client = client(&server, &cache)?;
+
+        dataflow_count(&client).await?;
+        cache.invalidate(&format!("{}/dataflow/ABS", server.uri()))?;
+        dataflow_count(&client).await?;
+
+        let requests = received(&server).await;
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].headers.get("If-None-Match").is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_responses_are_not_cached() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(header_exists("Accept"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+
+        let cache = HttpCache::new(dir.path(), Duration::from_secs(3600))?;
+        let client = client(&server, &cache)?;
+
+        assert!(dataflow_count(&client).await.is_err());
+        assert!(dataflow_count(&client).await.is_err());
+        assert_eq!(received(&server).await.len(), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn corrupt_entries_are_refetched() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let server = server().await;
+        let cache = HttpCache::new(dir.path(), Duration::from_secs(3600))?;
+        let client = client(&server, &cache)?;
+
+        let expected = dataflow_count(&client).await?;
+
+        for file in std::fs::read_dir(dir.path())? {
+            let path = file?.path();
+            let json = std::fs::read(&path)?;
+            std::fs::write(&path, &json[..json.len() / 2])?;
+        }
+
+        assert_eq!(dataflow_count(&client).await?, expected);
+        assert_eq!(dataflow_count(&client).await?, expected);
+
+        let requests = received(&server).await;
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].headers.get("If-None-Match").is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn entries_are_written_without_leftover_files() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let server = server().await;
+        let cache = HttpCache::new(dir.path(), Duration::from_secs(3600))?;
+        let client = client(&server, &cache)?;
+
+        dataflow_count(&client).await?;
+
+        let files = std::fs::read_dir(dir.path())?
+            .map(|file| Ok(file?.file_name().to_string_lossy().into_owned()))
+            .collect::<std::io::Result<Vec<_>>>()?;
+
+        assert_eq!(files.len(), 1);
+        assert!(files[0].ends_with(".json"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use abs_data::{
+        builders::{
+            dataflow_identifier_builder::DataflowIdentifierBuilder,
+            sdmx_client_builder::SdmxClientBuilder,
+        },
+        error_code::ErrorCode,
+        models::typed::{datakey_dimension::DataKeyDimension, detail::Detail},
+        result::Result,
+        transport::{
+            cassette_transport::{Cassette, CassetteTransport},
+            memory_transport::MemoryTransport,
+        },
+    };
+
+    const CPI_CONSTRAINTS: &[u8] = include_bytes!("fixtures/cpi_constraints.json");
+    const CPI_DATA: &[u8] = include_bytes!("fixtures/cpi_data.json");
+
+    fn upstream() -> MemoryTransport {
+        MemoryTransport::new()
+            .route(
+                "https://api.data.abs.gov.au/dataflow/ABS/CPI?references=descendants",
+                CPI_CONSTRAINTS,
+            )
+            .route(
+                "https://api.data.abs.gov.au/data/ABS,CPI/1.40066.10.8.Q?detail=dataonly",
+                CPI_DATA,
+            )
+    }
+
+    #[tokio::test]
+    async fn recorded_cassette_replays_without_upstream() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("cpi.json");
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+
+        let recorder = SdmxClientBuilder::new()
+            .key("secret")
+            .transport(CassetteTransport::record(&path, upstream()))
+            .build()?;
+
+        let key = recorder
+            .datakey(&dataflow_identifier)
+            .add(&DataKeyDimension::new("MEASURE", "1"))
+            .add(&DataKeyDimension::new("INDEX", "40066"))
+            .add(&DataKeyDimension::new("REGION", "8"))
+            .add(&DataKeyDimension::new("FREQ", "Q"))
+            .add(&DataKeyDimension::new("TSEST", "10"))
+            .build()
+            .await?;
+
+        let recorded = recorder
+            .data_request(&dataflow_identifier)
+            .data_key(&key)
+            .detail(&Detail::DataOnly)
+            .build()?
+            .send()
+            .await?;
+
+        let cassette = Cassette::load(&path)?;
+        assert_eq!(cassette.interactions.len(), 2);
+        assert_eq!(
+            cassette.interactions[0].request.headers.get("x-api-key"),
+            Some(&"[REDACTED]".into())
+        );
+
+        let player = SdmxClientBuilder::new()
+            .transport(CassetteTransport::replay(&path)?)
+            .build()?;
+
+        let replayed_key = player
+            .datakey(&dataflow_identifier)
+            .add(&DataKeyDimension::new("MEASURE", "1"))
+            .add(&DataKeyDimension::new("INDEX", "40066"))
+            .add(&DataKeyDimension::new("REGION", "8"))
+            .add(&DataKeyDimension::new("FREQ", "Q"))
+            .add(&DataKeyDimension::new("TSEST", "10"))
+            .build()
+            .await?;
+
+        let replayed = player
+            .data_request(&dataflow_identifier)
+            .data_key(&replayed_key)
+            .detail(&Detail::DataOnly)
+            .build()?
+            .send()
+            .await?;
+
+        assert_eq!(key, replayed_key);
+        assert_eq!(recorded, replayed);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn replay_fails_on_unknown_request() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("empty.json");
+        Cassette::default().save(&path)?;
+
+        let player = SdmxClientBuilder::new()
+            .transport(CassetteTransport::replay(&path)?)
+            .build()?;
+
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+        let result = player
+            .data_request(&dataflow_identifier)
+            .build()?
+            .send()
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ErrorCode::CassetteInteractionNotFound(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+//! Fixtures shared by the integration tests.
+
+use abs_data::result::Result;
+
+const CPI_DATA_V2: &[u8] = include_bytes!("../fixtures/cpi_data_v2.json");
+
+/// The 2.0 fixture with a second structure, whose first dimension is renamed, and
+/// its data sets referring to the given structures. As written from a
+/// `serde_json::Value`, the data sets come before the structures and each data set's
+/// structure index after its series.
+pub fn with_structures(data_set_structures: &[usize]) -> Result<Vec<u8>> {
+    let mut message: serde_json::Value = serde_json::from_slice(CPI_DATA_V2)?;
+    let data = &mut message["data"];
+
+    let mut other = data["structures"][0].clone();
+    other["dimensions"]["series"][0]["id"] = "OTHER".into();
+    data["structures"].as_array_mut().unwrap().push(other);
+
+    let data_set = data["dataSets"][0].clone();
+    data["dataSets"] = data_set_structures
+        .iter()
+        .map(|structure| {
+            let mut data_set = data_set.clone();
+            data_set["structure"] = (*structure).into();
+            data_set
+        })
+        .collect();
+
+    Ok(serde_json::to_vec(&message)?)
+}
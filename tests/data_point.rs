@@ -0,0 +1,157 @@
+#[cfg(test)]
+mod tests {
+    use abs_data::{
+        models::{derived::data_point::DataPoint, typed::missing_value::MissingValue},
+        result::Result,
+    };
+    use serde::Deserialize;
+
+    #[test]
+    fn data_points_accept_every_json_scalar() -> Result<()> {
+        let data_points: Vec<DataPoint> =
+            serde_json::from_str(r#"[130.8, -3, 42, true, null, "1.50"]"#)?;
+
+        let lexical = data_points
+            .iter()
+            .map(DataPoint::lexical)
+            .collect::<Vec<_>>();
+
+        assert_eq!(lexical, ["130.8", "-3", "42", "true", "null", "1.50"]);
+        assert_eq!(data_points[0].as_f64(), Some(130.8));
+        assert_eq!(data_points[1].as_i64(), Some(-3));
+        assert_eq!(data_points[3].as_bool(), Some(true));
+        assert_eq!(data_points[4].as_f64(), None);
+        assert_eq!(data_points[5].as_f64(), Some(1.5));
+        assert_eq!(data_points[5], DataPoint::from("1.5"));
+        assert_ne!(data_points[4], DataPoint::from("0"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn json_numbers_keep_their_lexical_form() -> Result<()> {
+        let data_points: Vec<DataPoint> = serde_json::from_str(
+            r#"[1.50, 1e3, 12345678901234567890.123456789, -0.0, "a \"quoted\" note"]"#,
+        )?;
+
+        let lexical = data_points
+            .iter()
+            .map(DataPoint::lexical)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            lexical,
+            [
+                "1.50",
+                "1e3",
+                "12345678901234567890.123456789",
+                "-0.0",
+                "a \"quoted\" note"
+            ]
+        );
+        assert_eq!(data_points[1].as_f64(), Some(1000.0));
+        assert!(serde_json::from_str::<DataPoint>("[1]").is_err());
+        assert!(serde_json::from_str::<DataPoint>(r#"{"a": 1}"#).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn data_points_are_read_from_other_formats() {
+        #[derive(Deserialize)]
+        struct Row {
+            value: Option<DataPoint>,
+        }
+
+        let rows = csv::Reader::from_reader("value\n1.50\n..\ntrue\n".as_bytes())
+            .deserialize()
+            .collect::<std::result::Result<Vec<Row>, _>>()
+            .expect("csv rows");
+
+        let values = rows
+            .into_iter()
+            .map(|row| row.value.expect("value"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(values[0], DataPoint::from("1.50"));
+        assert_eq!(values[1].missing(), Some(MissingValue::NotAvailable));
+        assert_eq!(values[2].as_bool(), Some(true));
+    }
+
+    #[test]
+    fn numbers_compare_by_their_exact_value() {
+        assert_eq!(DataPoint::from("129.0"), DataPoint::from("1.29e2"));
+        assert_eq!(DataPoint::from("-0.0"), DataPoint::from("0"));
+        assert_ne!(
+            DataPoint::from("0.1"),
+            DataPoint::from("0.10000000000000001")
+        );
+        assert_ne!(DataPoint::from("NaN"), DataPoint::from(".."));
+    }
+
+    #[test]
+    fn missing_values_are_told_apart_from_zero() {
+        let zero = DataPoint::from("0");
+        assert_eq!(zero.missing(), None);
+        assert_eq!(zero.as_f64(), Some(0.0));
+
+        for marker in ["NaN", "null", "..", ""] {
+            let data_point = DataPoint::from(marker);
+            assert_eq!(data_point.missing(), Some(MissingValue::NotAvailable));
+            assert_eq!(data_point.as_f64(), None);
+        }
+
+        for marker in ["NaN", "null"] {
+            let data_point = DataPoint::from(marker);
+            assert_eq!(data_point.as_i64(), None);
+            assert_eq!(data_point.as_bool(), None);
+        }
+
+        let confidential = DataPoint::from("np");
+        assert_eq!(confidential.missing(), Some(MissingValue::Confidential));
+        assert!(confidential.is_missing());
+        assert_eq!(confidential.as_f64(), None);
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn data_points_convert_to_exact_decimals() {
+        use std::str::FromStr;
+
+        use bigdecimal::BigDecimal;
+
+        assert_eq!(
+            DataPoint::from("0.1").as_decimal(),
+            Some(BigDecimal::from_str("0.1").expect("decimal"))
+        );
+        assert_eq!(DataPoint::from("..").as_decimal(), None);
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn json_numbers_keep_their_exact_digits() -> Result<()> {
+        use std::str::FromStr;
+
+        use bigdecimal::BigDecimal;
+
+        let data_points: Vec<DataPoint> =
+            serde_json::from_str("[1.50, 12345678901234567890.123456789, 1e-3]")?;
+
+        let lexical = data_points
+            .iter()
+            .map(DataPoint::lexical)
+            .collect::<Vec<_>>();
+
+        assert_eq!(lexical, ["1.50", "12345678901234567890.123456789", "1e-3"]);
+        assert_eq!(
+            data_points[1].as_decimal(),
+            Some(BigDecimal::from_str("12345678901234567890.123456789").expect("decimal"))
+        );
+        assert_eq!(
+            data_points[2].as_decimal(),
+            Some(BigDecimal::from_str("0.001").expect("decimal"))
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,258 @@
+#[cfg(test)]
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::with_structures;
+    use std::{
+        cell::Cell,
+        io::{self, Read},
+    };
+
+    use abs_data::{
+        builders::{
+            dataflow_identifier_builder::DataflowIdentifierBuilder,
+            sdmx_client_builder::SdmxClientBuilder,
+        },
+        error_code::ErrorCode,
+        models::typed::{data_format::DataFormat, time_series::TimeSeries},
+        parsers::{sdmx_csv, sdmx_json},
+        result::Result,
+        transport::memory_transport::MemoryTransport,
+    };
+
+    const CPI_DATA: &[u8] = include_bytes!("fixtures/cpi_data.json");
+    const CPI_DATA_V2: &[u8] = include_bytes!("fixtures/cpi_data_v2.json");
+    const CPI_DATA_CSV: &[u8] = include_bytes!("fixtures/cpi_data.csv");
+
+    const CPI_DATA_URL: &str = "https://api.data.abs.gov.au/data/ABS,CPI/all";
+
+    /// Hands over one byte per read, counting how many have been read.
+    struct Trickle<'a> {
+        bytes: &'a [u8],
+        read: &'a Cell<usize>,
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let position = self.read.get();
+            match (self.bytes.get(position), buf.first_mut()) {
+                (Some(byte), Some(slot)) => {
+                    *slot = *byte;
+                    self.read.set(position + 1);
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    fn read_all<F>(read_series: F) -> Result<Vec<TimeSeries>>
+    where
+        F: FnOnce(&mut dyn FnMut(TimeSeries) -> Result<()>) -> Result<()>,
+    {
+        let mut series = Vec::new();
+        read_series(&mut |s| {
+            series.push(s);
+            Ok(())
+        })?;
+        series.sort_by(|a, b| a.key().as_ref().cmp(b.key().as_ref()));
+        Ok(series)
+    }
+
+    /// The 2.0 fixture with its data set's structure index written before its series.
+    fn with_structure_first() -> Result<Vec<u8>> {
+        let message: serde_json::Value = serde_json::from_slice(CPI_DATA_V2)?;
+        let data = &message["data"];
+
+        Ok(format!(
+            r#"{{"meta":{},"data":{{"structures":{},"dataSets":[{{"structure":0,"series":{}}}]}}}}"#,
+            message["meta"], data["structures"], data["dataSets"][0]["series"]
+        )
+        .into_bytes())
+    }
+
+    #[test]
+    fn series_are_read_from_json_and_csv() -> Result<()> {
+        let expected = sdmx_json::parse_data(CPI_DATA)?.time_series()?;
+
+        for bytes in [CPI_DATA, CPI_DATA_V2] {
+            let series = read_all(|f| sdmx_json::read_series(bytes, f))?;
+            assert_eq!(series, expected.iter().cloned().collect::<Vec<_>>());
+        }
+
+        let series = read_all(|f| sdmx_csv::read_series(CPI_DATA_CSV, f))?;
+        assert_eq!(series.len(), 3);
+
+        let canberra = &series[1];
+        assert_eq!(canberra.key().as_ref(), "1.10001.10.8.Q");
+        assert_eq!(canberra.dimension("REGION"), Some("8"));
+        assert_eq!(
+            canberra.values(),
+            expected.get("1.10001.10.8.Q").expect("series").values()
+        );
+        assert_eq!(
+            canberra.observations()[1].attributes.get("OBS_STATUS"),
+            Some(&"R".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn series_are_handed_over_before_the_message_ends() -> Result<()> {
+        let bytes = with_structure_first()?;
+        let read = Cell::new(0);
+        let mut first = None;
+
+        sdmx_json::read_series(
+            Trickle {
+                bytes: &bytes,
+                read: &read,
+            },
+            |_| {
+                first.get_or_insert(read.get());
+                Ok(())
+            },
+        )?;
+
+        // The structure and the data set's structure index come first, so the first
+        // series is handed over before the second is read.
+        let second = std::str::from_utf8(&bytes)?
+            .find("\"0:0:0:1:0\"")
+            .expect("second series");
+        assert!(first.expect("a series") < second);
+
+        let read = Cell::new(0);
+        let mut count = 0;
+
+        let result = sdmx_csv::read_series(
+            Trickle {
+                bytes: CPI_DATA_CSV,
+                read: &read,
+            },
+            |_| {
+                count += 1;
+                Err(ErrorCode::Custom("stop".into()))
+            },
+        );
+
+        assert!(matches!(result, Err(ErrorCode::Custom(_))));
+        assert_eq!(count, 1);
+        assert!(read.get() < CPI_DATA_CSV.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn series_before_their_structure_are_held_until_it_is_read() -> Result<()> {
+        let bytes = with_structures(&[0])?;
+        let read = Cell::new(0);
+        let mut handed_over = Vec::new();
+
+        sdmx_json::read_series(
+            Trickle {
+                bytes: &bytes,
+                read: &read,
+            },
+            |_| {
+                handed_over.push(read.get());
+                Ok(())
+            },
+        )?;
+
+        // The data sets come before the structures, so no series is handed over, and
+        // memory is not bounded, until the structures have been read.
+        let structures = std::str::from_utf8(&bytes)?
+            .find("\"structures\"")
+            .expect("structures");
+        let series = std::str::from_utf8(&bytes)?
+            .find("\"series\"")
+            .expect("series");
+
+        assert!(series < structures);
+        assert!(!handed_over.is_empty());
+        assert!(handed_over.iter().all(|read| *read > structures));
+
+        Ok(())
+    }
+
+    #[test]
+    fn series_resolve_against_their_data_sets_structure() -> Result<()> {
+        let expected = sdmx_json::parse_data(CPI_DATA)?.time_series()?;
+
+        for data_set_structures in [&[1][..], &[1, 0]] {
+            let bytes = with_structures(data_set_structures)?;
+            let series = read_all(|f| sdmx_json::read_series(&bytes[..], f))?;
+
+            let other = series
+                .iter()
+                .filter(|series| series.dimension("OTHER").is_some())
+                .count();
+
+            assert_eq!(series.len(), expected.len() * data_set_structures.len());
+            assert_eq!(other, expected.len());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn data_sets_before_their_structure_are_read() -> Result<()> {
+        // The 1.0 fixture has its data sets before the structure.
+        let mut message: serde_json::Value = serde_json::from_slice(CPI_DATA)?;
+        message.as_object_mut().unwrap().remove("meta");
+        let expected = sdmx_json::parse_data(CPI_DATA)?.time_series()?;
+
+        for bytes in [CPI_DATA.to_vec(), serde_json::to_vec(&message)?] {
+            let series = read_all(|f| sdmx_json::read_series(&bytes[..], f))?;
+            assert_eq!(series, expected.iter().cloned().collect::<Vec<_>>());
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn requests_stream_series() -> Result<()> {
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI")
+            .agency_id("ABS")
+            .build();
+
+        for (format, body) in [
+            (DataFormat::SdmxJson, CPI_DATA),
+            (DataFormat::SdmxCsv, CPI_DATA_CSV),
+        ] {
+            let transport = MemoryTransport::new().route(CPI_DATA_URL, body);
+            let client = SdmxClientBuilder::new().transport(transport).build()?;
+            let request = client
+                .data_request(&dataflow_identifier)
+                .format(format)
+                .build()?;
+
+            let mut stream = request.send_stream().await?;
+            let mut keys = Vec::new();
+            while let Some(series) = stream.next().await {
+                keys.push(series?.key().to_string());
+            }
+            keys.sort();
+
+            assert_eq!(keys, ["1.10001.10.1.Q", "1.10001.10.8.Q", "3.10001.10.8.Q"]);
+        }
+
+        let transport = MemoryTransport::new().route(CPI_DATA_URL, Vec::new());
+        let client = SdmxClientBuilder::new().transport(transport).build()?;
+        let mut stream = client
+            .data_request(&dataflow_identifier)
+            .build()?
+            .send_stream()
+            .await?;
+
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(ErrorCode::HttpEmptyResponse))
+        ));
+        assert!(stream.next().await.is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+    use abs_data::{
+        error_code::ErrorCode,
+        models::derived::{data_sets::DataSets, flat_observation::ResolvedComponent},
+        parsers::sdmx_json,
+        result::Result,
+    };
+
+    const CPI_DATA: &[u8] = include_bytes!("fixtures/cpi_data.json");
+
+    #[test]
+    fn observations_resolve_keys_against_the_structure() -> Result<()> {
+        let response = sdmx_json::parse_data(CPI_DATA)?;
+
+        let observations = response.observations()?.collect::<Vec<_>>();
+
+        assert_eq!(observations.len(), 8);
+
+        let first = &observations[0];
+        assert_eq!(first.series_key, "0:0:0:0:0");
+        assert_eq!(first.time_period, Some("2023-Q1"));
+        assert_eq!(first.value, Some(130.8));
+        assert_eq!(first.dimensions.len(), 5);
+        assert_eq!(
+            first.dimension("REGION"),
+            Some(&ResolvedComponent {
+                id: "REGION",
+                code: "1",
+                label: "Sydney",
+            })
+        );
+        assert_eq!(first.attribute("UNIT_MEASURE").map(|a| a.code), Some("IN"));
+        assert_eq!(first.attribute("OBS_STATUS"), None);
+
+        let estimated = &observations[2];
+        assert_eq!(estimated.time_period, Some("2023-Q3"));
+        assert_eq!(
+            estimated.attribute("OBS_STATUS").map(|a| a.label),
+            Some("Estimated value")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn observations_are_ordered_by_series_and_time() -> Result<()> {
+        let response = sdmx_json::parse_data(CPI_DATA)?;
+
+        let keys = response
+            .observations()?
+            .map(|o| (o.series_key, o.time_period.unwrap_or_default()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            keys,
+            [
+                ("0:0:0:0:0", "2023-Q1"),
+                ("0:0:0:0:0", "2023-Q2"),
+                ("0:0:0:0:0", "2023-Q3"),
+                ("0:0:0:1:0", "2023-Q1"),
+                ("0:0:0:1:0", "2023-Q2"),
+                ("0:0:0:1:0", "2023-Q3"),
+                ("1:0:0:1:0", "2023-Q1"),
+                ("1:0:0:1:0", "2023-Q3"),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn observations_need_a_structure() {
+        let data_sets = DataSets::default();
+
+        assert!(matches!(
+            data_sets.observations(),
+            Err(ErrorCode::MissingExpectedOptionalField(_))
+        ));
+    }
+}
@@ -6,14 +6,46 @@ mod tests {
             datakey_builder::DataKeyBuilder, sdmx_data_request_builder::SdmxDataRequestBuilder,
             sdmx_meta_request_builder::SdmxMetaRequestBuilder,
         },
+        error_code::ErrorCode,
         models::typed::{
             datakey::DataKey, datakey_dimension::DataKeyDimension, detail::Detail, period::Period,
-            structure_type::StructureType,
+            sdmx_client::SdmxClient, structure_type::StructureType,
         },
         result::Result,
+        transport::memory_transport::MemoryTransport,
     };
+    use chrono::{DateTime, Utc};
+
+    const DATAFLOWS: &[u8] = include_bytes!("fixtures/dataflows.json");
+    const CPI_CONSTRAINTS: &[u8] = include_bytes!("fixtures/cpi_constraints.json");
+    const CPI_DATA: &[u8] = include_bytes!("fixtures/cpi_data.json");
+
+    fn offline_client() -> Result<SdmxClient> {
+        let transport = MemoryTransport::new()
+            .route("https://api.data.abs.gov.au/dataflow/ABS", DATAFLOWS)
+            .route(
+                "https://api.data.abs.gov.au/dataflow/ABS/CPI?references=descendants",
+                CPI_CONSTRAINTS,
+            )
+            .route(
+                "https://api.data.abs.gov.au/dataflow/ABS/CPI/1.1.0?references=descendants",
+                CPI_CONSTRAINTS,
+            )
+            .route("https://api.data.abs.gov.au/data/ABS,CPI/all", CPI_DATA)
+            .route(
+                "https://api.data.abs.gov.au/data/ABS,CPI,1.1.0/all?startPeriod=2012&endPeriod=2022&detail=dataonly",
+                CPI_DATA,
+            )
+            .route(
+                "https://api.data.abs.gov.au/data/ABS,CPI/1.40066.10.8.Q?detail=dataonly",
+                CPI_DATA,
+            );
+
+        SdmxClient::builder().transport(transport).build()
+    }
 
     #[tokio::test]
+    #[ignore = "requires network access to the live ABS API"]
     async fn get_dynamic_meta_and_use_for_request() -> Result<()> {
         let meta_response = SdmxMetaRequestBuilder::new(&StructureType::DataFlow)
             .build()
@@ -31,32 +63,81 @@ mod tests {
             .detail(&Detail::DataOnly)
             .start_period(&Period::Year(2012))
             .end_period(&Period::Year(2022))
+            .build()?
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn offline_get_dynamic_meta_and_use_for_request() -> Result<()> {
+        let client = offline_client()?;
+
+        let meta_response = client
+            .meta_request(&StructureType::DataFlow)
             .build()
             .send()
             .await?;
 
+        let dataflow = &meta_response.data.dataflows.unwrap()[0];
+
+        let dataflow_identifier = DataflowIdentifierBuilder::new(&dataflow.id)
+            .agency_id(&dataflow.agency_id)
+            .version(&dataflow.version)
+            .build();
+
+        let response = client
+            .data_request(&dataflow_identifier)
+            .detail(&Detail::DataOnly)
+            .start_period(&Period::Year(2012))
+            .end_period(&Period::Year(2022))
+            .build()?
+            .send()
+            .await?;
+
+        assert_eq!(response.data.data_sets[0].series.len(), 3);
+
         Ok(())
     }
 
     #[tokio::test]
+    #[ignore = "requires network access to the live ABS API"]
     async fn get_all_data_for_structure_id_without_filter() -> Result<()> {
         let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
 
         let _response = SdmxDataRequestBuilder::new(&dataflow_identifier) // Avoid 500 response with data only detail (issue with beta api)
-            .build()
+            .build()?
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn offline_get_all_data_for_structure_id_without_filter() -> Result<()> {
+        let client = offline_client()?;
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+
+        let response = client
+            .data_request(&dataflow_identifier)
+            .build()?
             .send()
             .await?;
 
+        assert!(response.data.structure.is_some());
+
         Ok(())
     }
 
     #[tokio::test]
+    #[ignore = "requires network access to the live ABS API"]
     async fn get_datakeys_for_structure_id() -> Result<()> {
         let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
 
         let _response = SdmxDataRequestBuilder::new(&dataflow_identifier)
             .detail(&Detail::SeriesKeysOnly)
-            .build()
+            .build()?
             .send()
             .await?;
 
@@ -64,13 +145,14 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "requires network access to the live ABS API"]
     async fn get_data_with_custom_datakey() -> Result<()> {
         let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
 
         let _response = SdmxDataRequestBuilder::new(&dataflow_identifier)
             .data_key(&DataKey::parse("1.40066.10.8.Q")?)
             .detail(&Detail::DataOnly)
-            .build()
+            .build()?
             .send()
             .await?;
 
@@ -78,6 +160,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "requires network access to the live ABS API"]
     async fn get_data_with_datakey_builder_validation() -> Result<()> {
         let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
 
@@ -95,10 +178,144 @@ mod tests {
         let _response = SdmxDataRequestBuilder::new(&dataflow_identifier)
             .data_key(&key)
             .detail(&Detail::DataOnly)
+            .build()?
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn offline_get_data_with_datakey_builder_validation() -> Result<()> {
+        let client = offline_client()?;
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+
+        let key = client
+            .datakey(&dataflow_identifier)
+            .add(&DataKeyDimension::new("MEASURE", "1"))
+            .add(&DataKeyDimension::new("INDEX", "40066"))
+            .add(&DataKeyDimension::new("REGION", "8"))
+            .add(&DataKeyDimension::new("FREQ", "Q"))
+            .add(&DataKeyDimension::new("TSEST", "10"))
             .build()
+            .await?;
+
+        assert_eq!(key, DataKey::parse("1.40066.10.8.Q")?);
+
+        let _response = client
+            .data_request(&dataflow_identifier)
+            .data_key(&key)
+            .detail(&Detail::DataOnly)
+            .build()?
             .send()
             .await?;
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn offline_datakey_builder_rejects_unknown_dimension_values() -> Result<()> {
+        let client = offline_client()?;
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+
+        let result = client
+            .datakey(&dataflow_identifier)
+            .add(&DataKeyDimension::new("REGION", "999"))
+            .build()
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ErrorCode::DataKeyContainsInvalidDimensions(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn clients_build_requests_against_their_own_base_url() -> Result<()> {
+        let production = SdmxClient::builder().build()?;
+        let staging = SdmxClient::builder()
+            .base_url("https://staging.example.com")
+            .key("staging-key")
+            .build()?;
+
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+
+        let production_request = production.data_request(&dataflow_identifier).build()?;
+        let staging_request = staging.data_request(&dataflow_identifier).build()?;
+
+        assert_eq!(
+            production_request.url(),
+            "https://api.data.abs.gov.au/data/ABS,CPI/all"
+        );
+        assert_eq!(
+            staging_request.url(),
+            "https://staging.example.com/data/ABS,CPI/all"
+        );
+
+        let meta_request = staging.meta_request(&StructureType::DataFlow).build();
+
+        assert_eq!(
+            meta_request.url(),
+            "https://staging.example.com/dataflow/ABS"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn observation_and_history_filters_are_added_to_the_query() -> Result<()> {
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+        let updated_after = DateTime::parse_from_rfc3339("2024-01-31T11:30:00+10:00")
+            .map_err(|e| ErrorCode::Custom(e.to_string().into()))?
+            .with_timezone(&Utc);
+
+        let request = SdmxDataRequestBuilder::new(&dataflow_identifier)
+            .last_n_observations(4)
+            .updated_after_timestamp(updated_after)
+            .include_history(true)
+            .build()?;
+
+        assert_eq!(
+            request.url(),
+            "https://api.data.abs.gov.au/data/ABS,CPI/all\
+             ?updatedAfter=2024-01-31T01:30:00Z&lastNObservations=4&includeHistory=true"
+        );
+
+        let request = SdmxDataRequestBuilder::new(&dataflow_identifier)
+            .first_n_observations(1)
+            .build()?;
+
+        assert_eq!(
+            request.url(),
+            "https://api.data.abs.gov.au/data/ABS,CPI/all?firstNObservations=1"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn client_builder_rejects_invalid_base_url() {
+        assert!(SdmxClient::builder().base_url("not a url").build().is_err());
+    }
+
+    #[tokio::test]
+    async fn offline_unknown_url_is_not_found() -> Result<()> {
+        let client = offline_client()?;
+        let dataflow_identifier = DataflowIdentifierBuilder::new("LF").build();
+
+        let result = client
+            .data_request(&dataflow_identifier)
+            .build()?
+            .send()
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ErrorCode::Http(status)) if status == reqwest::StatusCode::NOT_FOUND
+        ));
+
+        Ok(())
+    }
 }
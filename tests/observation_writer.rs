@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests {
+    use abs_data::{
+        error_code::ErrorCode, exporters::observation_writer::ObservationWriter,
+        parsers::sdmx_json, result::Result,
+    };
+    use serde_json::{json, Value};
+
+    const CPI_DATA: &[u8] = include_bytes!("fixtures/cpi_data.json");
+
+    #[test]
+    fn observations_write_as_csv() -> Result<()> {
+        let response = sdmx_json::parse_data(CPI_DATA)?;
+
+        let mut csv = Vec::new();
+        response.write_csv(&mut csv)?;
+        let csv = String::from_utf8(csv).expect("utf-8");
+        let lines = csv.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 9);
+        assert_eq!(
+            lines[0],
+            "MEASURE,INDEX,TSEST,REGION,FREQ,TIME_PERIOD,OBS_VALUE,UNIT_MEASURE,OBS_STATUS"
+        );
+        assert_eq!(lines[1], "1,10001,10,1,Q,2023-Q1,130.8,IN,");
+        assert_eq!(lines[3], "1,10001,10,1,Q,2023-Q3,133.5,IN,E");
+
+        let mut csv = Vec::new();
+        ObservationWriter::builder()
+            .labels(true)
+            .attributes(&["OBS_STATUS"])
+            .delimiter(b';')
+            .build()
+            .write_csv(&response.data, &mut csv)?;
+        let csv = String::from_utf8(csv).expect("utf-8");
+        let lines = csv.lines().collect::<Vec<_>>();
+
+        assert!(lines[0].starts_with("MEASURE;MEASURE_LABEL;INDEX;INDEX_LABEL;"));
+        assert!(lines[0].ends_with(";TIME_PERIOD;OBS_VALUE;OBS_STATUS;OBS_STATUS_LABEL"));
+        assert_eq!(
+            lines[3],
+            "1;Index Numbers;10001;All groups CPI;10;Original;1;Sydney;Q;Quarterly;2023-Q3;133.5;E;Estimated value"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn observations_write_as_ndjson() -> Result<()> {
+        let response = sdmx_json::parse_data(CPI_DATA)?;
+
+        let mut ndjson = Vec::new();
+        ObservationWriter::builder()
+            .labels(true)
+            .attributes(&["OBS_STATUS"])
+            .build()
+            .write_ndjson(&response.data, &mut ndjson)?;
+        let ndjson = String::from_utf8(ndjson).expect("utf-8");
+        let rows = ndjson
+            .lines()
+            .map(serde_json::from_str::<Value>)
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        assert_eq!(rows.len(), 8);
+        assert_eq!(
+            rows[6],
+            json!({
+                "MEASURE": "3",
+                "MEASURE_LABEL": "Percentage Change from Corresponding Quarter of Previous Year",
+                "INDEX": "10001",
+                "INDEX_LABEL": "All groups CPI",
+                "TSEST": "10",
+                "TSEST_LABEL": "Original",
+                "REGION": "8",
+                "REGION_LABEL": "Canberra",
+                "FREQ": "Q",
+                "FREQ_LABEL": "Quarterly",
+                "TIME_PERIOD": "2023-Q1",
+                "OBS_VALUE": 6.9,
+                "OBS_STATUS": null,
+                "OBS_STATUS_LABEL": null,
+            })
+        );
+
+        let keys = ndjson.lines().next().expect("row");
+        assert!(keys.find("\"TIME_PERIOD\"") < keys.find("\"OBS_VALUE\""));
+
+        Ok(())
+    }
+
+    #[test]
+    fn values_are_written_the_same_in_both_formats() -> Result<()> {
+        let message = std::str::from_utf8(CPI_DATA)?
+            .replacen("130.8,", "130.80,", 1)
+            .replacen("131.6,", "\"NaN\",", 1);
+        let response = sdmx_json::parse_data(message.as_bytes())?;
+
+        let mut csv = Vec::new();
+        response.write_csv(&mut csv)?;
+        let csv = String::from_utf8(csv).expect("utf-8");
+        let lines = csv.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines[1], "1,10001,10,1,Q,2023-Q1,130.80,IN,");
+        assert_eq!(lines[2], "1,10001,10,1,Q,2023-Q2,NaN,IN,");
+
+        let mut ndjson = Vec::new();
+        response.write_ndjson(&mut ndjson)?;
+        let ndjson = String::from_utf8(ndjson).expect("utf-8");
+        let lines = ndjson.lines().collect::<Vec<_>>();
+
+        assert!(lines[0].contains(r#""OBS_VALUE":130.80,"#));
+        assert!(lines[1].contains(r#""OBS_VALUE":"NaN","#));
+        assert!(lines[0].contains(r#""OBS_STATUS":null"#));
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_attributes_are_rejected() -> Result<()> {
+        let response = sdmx_json::parse_data(CPI_DATA)?;
+        let writer = ObservationWriter::builder()
+            .attributes(&["OBS_STATUS", "OBS_STATS"])
+            .build();
+
+        let mut csv = Vec::new();
+        let result = writer.write_csv(&response.data, &mut csv);
+
+        assert!(matches!(
+            result,
+            Err(ErrorCode::UnknownAttribute(id)) if id.as_ref() == "OBS_STATS"
+        ));
+        assert!(csv.is_empty());
+
+        let mut ndjson = Vec::new();
+        assert!(writer.write_ndjson(&response.data, &mut ndjson).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+#[cfg(all(test, feature = "parquet"))]
+mod tests {
+    use std::{collections::HashMap, fs::File};
+
+    use abs_data::{
+        builders::{
+            dataflow_identifier_builder::DataflowIdentifierBuilder,
+            sdmx_client_builder::SdmxClientBuilder,
+        },
+        exporters::parquet::{
+            METADATA_AGENCY_ID, METADATA_DATAFLOW_ID, METADATA_PREPARED, METADATA_VERSION,
+        },
+        result::Result,
+        transport::memory_transport::MemoryTransport,
+    };
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+
+    const CPI_DATA: &[u8] = include_bytes!("fixtures/cpi_data.json");
+    const CPI_DATA_V2: &[u8] = include_bytes!("fixtures/cpi_data_v2.json");
+    const DATAFLOWS: &[u8] = include_bytes!("fixtures/dataflows.json");
+    const CPI_DATA_URL: &str = "https://api.data.abs.gov.au/data/ABS,CPI/all";
+
+    #[tokio::test]
+    async fn data_requests_save_to_parquet_with_file_metadata() -> Result<()> {
+        let transport = MemoryTransport::new().route(CPI_DATA_URL, CPI_DATA);
+        let client = SdmxClientBuilder::new().transport(transport).build()?;
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI")
+            .agency_id("ABS")
+            .build();
+
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("cpi.parquet");
+
+        let response = client
+            .data_request(&dataflow_identifier)
+            .build()?
+            .save_parquet(&path)
+            .await?;
+
+        assert_eq!(response.data.data_sets.len(), 1);
+
+        let reader = SerializedFileReader::new(File::open(&path)?)?;
+        let file_metadata = reader.metadata().file_metadata();
+
+        assert_eq!(file_metadata.num_rows(), 8);
+
+        let metadata = file_metadata
+            .key_value_metadata()
+            .expect("key value metadata")
+            .iter()
+            .filter_map(|kv| Some((kv.key.as_str(), kv.value.as_deref()?)))
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(metadata[METADATA_DATAFLOW_ID], "CPI");
+        assert_eq!(metadata[METADATA_AGENCY_ID], "ABS");
+        assert_eq!(metadata[METADATA_PREPARED], "2023-10-01T00:00:00Z");
+        assert_eq!(metadata[METADATA_VERSION], "1.1.0");
+        assert!(metadata["labels.REGION"].contains(r#""1":"Sydney""#));
+
+        let files = std::fs::read_dir(dir.path())?.count();
+        assert_eq!(files, 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unlinked_responses_take_the_version_from_the_dataflow() -> Result<()> {
+        let transport = MemoryTransport::new()
+            .route(CPI_DATA_URL, CPI_DATA_V2)
+            .route("https://api.data.abs.gov.au/dataflow/ABS/CPI", DATAFLOWS);
+        let client = SdmxClientBuilder::new().transport(transport).build()?;
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("cpi.parquet");
+
+        client
+            .data_request(&dataflow_identifier)
+            .build()?
+            .save_parquet(&path)
+            .await?;
+
+        let reader = SerializedFileReader::new(File::open(&path)?)?;
+        let version = reader
+            .metadata()
+            .file_metadata()
+            .key_value_metadata()
+            .expect("key value metadata")
+            .iter()
+            .find(|kv| kv.key == METADATA_VERSION)
+            .and_then(|kv| kv.value.clone());
+
+        assert_eq!(version.as_deref(), Some("1.1.0"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unresolved_versions_fail_without_writing_a_file() -> Result<()> {
+        let transport = MemoryTransport::new().route(CPI_DATA_URL, CPI_DATA_V2);
+        let client = SdmxClientBuilder::new().transport(transport).build()?;
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("cpi.parquet");
+
+        let result = client
+            .data_request(&dataflow_identifier)
+            .build()?
+            .save_parquet(&path)
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(std::fs::read_dir(dir.path())?.count(), 0);
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn concurrent_saves_to_one_path_leave_one_file() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("cpi.parquet");
+
+        let saves = (0..8).map(|_| {
+            let path = path.clone();
+            tokio::spawn(async move {
+                let transport = MemoryTransport::new().route(CPI_DATA_URL, CPI_DATA);
+                let client = SdmxClientBuilder::new().transport(transport).build()?;
+                let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+
+                client
+                    .data_request(&dataflow_identifier)
+                    .build()?
+                    .save_parquet(&path)
+                    .await
+                    .map(|_| ())
+            })
+        });
+
+        for saved in futures::future::join_all(saves).await {
+            saved.expect("save task")?;
+        }
+
+        let reader = SerializedFileReader::new(File::open(&path)?)?;
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 8);
+        assert_eq!(std::fs::read_dir(dir.path())?.count(), 1);
+
+        Ok(())
+    }
+}
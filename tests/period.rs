@@ -0,0 +1,205 @@
+#[cfg(test)]
+mod tests {
+    use abs_data::{
+        error_code::ErrorCode,
+        models::typed::{
+            frequency::Frequency, month::Month, period::Period, quarter::Quarter,
+            semester::Semester, trimester::Trimester,
+        },
+        result::Result,
+    };
+    use chrono::NaiveDate;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).expect("valid date")
+    }
+
+    #[test]
+    fn periods_round_trip_through_display() -> Result<()> {
+        let periods = [
+            ("2012", Period::Year(2012)),
+            ("2012-S2", Period::YearSemester(2012, Semester::Second)),
+            ("2012-Q1", Period::YearQuarter(2012, Quarter::First)),
+            ("2012-03", Period::YearMonth(2012, Month::Mar)),
+            ("2012-W05", Period::YearWeek(2012, 5)),
+            ("2012-03-14", Period::YearMonthDay(2012, Month::Mar, 14)),
+            ("2012-A1", Period::ReportingYear(2012)),
+            ("2012-T2", Period::YearTrimester(2012, Trimester::Second)),
+            ("2012-D366", Period::ReportingDay(2012, 366)),
+        ];
+
+        for (str, period) in periods {
+            assert_eq!(str.parse::<Period>()?, period);
+            assert_eq!(period.to_string(), str);
+        }
+
+        assert_eq!(
+            Period::parse("2012-M03")?,
+            Period::YearMonth(2012, Month::Mar)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn period_components_parse() -> Result<()> {
+        assert_eq!("Q4".parse::<Quarter>()?, Quarter::Fourth);
+        assert_eq!("S1".parse::<Semester>()?, Semester::First);
+        assert_eq!("T3".parse::<Trimester>()?, Trimester::Third);
+        assert_eq!(Month::try_from("12")?, Month::Dec);
+
+        assert!("Q5".parse::<Quarter>().is_err());
+        assert!("13".parse::<Month>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_periods_are_rejected() {
+        let invalid = [
+            "",
+            "12",
+            "2012-",
+            "2012-Q5",
+            "2012-13",
+            "2012-3",
+            "2013-02-29",
+            "2012-W00",
+            "2021-W53",
+            "2013-D366",
+            "2012-A2",
+            "2012-X1",
+            "2012-Q1-01",
+        ];
+
+        for str in invalid {
+            match str.parse::<Period>() {
+                Err(ErrorCode::PeriodParse(input)) => assert_eq!(input.as_ref(), str),
+                other => panic!("expected {str} to be rejected, got {other:?}"),
+            }
+        }
+
+        assert!("2012-02-29".parse::<Period>().is_ok());
+        assert!("2020-W53".parse::<Period>().is_ok());
+    }
+
+    #[test]
+    fn parsed_periods_sort_by_time() -> Result<()> {
+        let mut periods = ["2012-Q3", "2011-Q4", "2012-Q1"]
+            .into_iter()
+            .map(str::parse)
+            .collect::<Result<Vec<Period>>>()?;
+
+        periods.sort();
+
+        assert_eq!(
+            periods,
+            [
+                Period::YearQuarter(2011, Quarter::Fourth),
+                Period::YearQuarter(2012, Quarter::First),
+                Period::YearQuarter(2012, Quarter::Third),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn periods_step_across_years() {
+        let q4 = Period::YearQuarter(2023, Quarter::Fourth);
+
+        assert_eq!(q4.next(), Period::YearQuarter(2024, Quarter::First));
+        assert_eq!(q4.prev(), Period::YearQuarter(2023, Quarter::Third));
+        assert_eq!(q4 - 7, Period::YearQuarter(2022, Quarter::First));
+        assert_eq!(
+            Period::YearMonth(2023, Month::Nov) + 14,
+            Period::YearMonth(2025, Month::Jan)
+        );
+        assert_eq!(
+            Period::YearSemester(2023, Semester::Second) + 1,
+            Period::YearSemester(2024, Semester::First)
+        );
+        assert_eq!(Period::YearWeek(2020, 52) + 1, Period::YearWeek(2020, 53));
+        assert_eq!(Period::YearWeek(2020, 53) + 1, Period::YearWeek(2021, 1));
+        assert_eq!(
+            Period::YearMonthDay(2024, Month::Feb, 28) + 2,
+            Period::YearMonthDay(2024, Month::Mar, 1)
+        );
+        assert_eq!(
+            Period::ReportingDay(2023, 365).next(),
+            Period::ReportingDay(2024, 1)
+        );
+        assert_eq!(Period::Year(0).checked_add(-1), None);
+    }
+
+    #[test]
+    fn period_ranges_are_inclusive() -> Result<()> {
+        let end = Period::YearQuarter(2023, Quarter::Fourth);
+        let window = Period::range(end - 7, end)?.collect::<Vec<_>>();
+
+        assert_eq!(window.len(), 8);
+        assert_eq!(window[0], Period::YearQuarter(2022, Quarter::First));
+        assert_eq!(window[7], end);
+
+        let months = Period::range(Period::YearMonth(2023, Month::Nov), Period::Year(2024))?
+            .map(|period| period.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(months, ["2023-11", "2023-12", "2024-01"]);
+
+        assert_eq!(Period::range(end, end - 1)?.count(), 0);
+        assert!(Period::range(Period::ReportingYear(2023), Period::Year(2024)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn periods_convert_to_calendar_dates() -> Result<()> {
+        let q1 = Period::YearQuarter(2024, Quarter::First);
+        assert_eq!(q1.first_date()?, date(2024, 1, 1));
+        assert_eq!(q1.last_date()?, date(2024, 3, 31));
+
+        let february = Period::YearMonth(2024, Month::Feb);
+        assert_eq!(february.last_date()?, date(2024, 2, 29));
+
+        let week = Period::YearWeek(2021, 1);
+        assert_eq!(week.first_date()?, date(2021, 1, 4));
+        assert_eq!(week.last_date()?, date(2021, 1, 10));
+
+        let trimester = Period::YearTrimester(2024, Trimester::Second);
+        assert_eq!(trimester.first_date()?, date(2024, 5, 1));
+        assert_eq!(trimester.last_date()?, date(2024, 8, 31));
+
+        assert!(matches!(
+            Period::YearWeek(2021, 53).first_date(),
+            Err(ErrorCode::InvalidPeriod(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn periods_convert_between_frequencies() -> Result<()> {
+        let may = Period::YearMonth(2024, Month::May);
+
+        assert_eq!(
+            may.to_frequency(Frequency::Quarterly)?,
+            Period::YearQuarter(2024, Quarter::Second)
+        );
+        assert_eq!(
+            may.to_frequency(Frequency::SemiAnnual)?,
+            Period::YearSemester(2024, Semester::First)
+        );
+        assert_eq!(may.to_frequency(Frequency::Annual)?, Period::Year(2024));
+        assert_eq!(
+            Period::YearQuarter(2024, Quarter::Third).to_frequency(Frequency::Monthly)?,
+            Period::YearMonth(2024, Month::Jul)
+        );
+        assert_eq!(
+            Period::containing(date(2021, 1, 1), Frequency::Weekly)?,
+            Period::YearWeek(2020, 53)
+        );
+        assert_eq!(may.frequency().to_string(), "M");
+
+        Ok(())
+    }
+}
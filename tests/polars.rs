@@ -0,0 +1,83 @@
+#[cfg(all(test, feature = "polars"))]
+mod tests {
+    use abs_data::{error_code::ErrorCode, parsers::sdmx_json, result::Result};
+    use polars::prelude::DataType;
+
+    const CPI_DATA: &[u8] = include_bytes!("fixtures/cpi_data.json");
+
+    #[test]
+    fn long_data_frames_have_a_row_per_observation() -> Result<()> {
+        let frame = sdmx_json::parse_data(CPI_DATA)?.to_data_frame()?;
+
+        assert_eq!(frame.shape(), (8, 9));
+        assert!(matches!(
+            frame.column("REGION")?.dtype(),
+            DataType::Categorical(..)
+        ));
+        assert_eq!(frame.column("TIME_PERIOD")?.dtype(), &DataType::Date);
+
+        let values = frame.column("OBS_VALUE")?.f64()?;
+        assert_eq!(values.get(0), Some(130.8));
+
+        let regions = frame
+            .column("REGION")?
+            .cast(&DataType::String)?
+            .str()?
+            .into_iter()
+            .map(Option::unwrap_or_default)
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(regions, ["1", "1", "1", "8", "8", "8", "8", "8"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn wide_data_frames_have_a_column_per_series() -> Result<()> {
+        let frame = sdmx_json::parse_data(CPI_DATA)?.to_wide_data_frame()?;
+
+        let names = frame
+            .get_column_names()
+            .into_iter()
+            .map(|name| name.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            names,
+            [
+                "TIME_PERIOD",
+                "1.10001.10.1.Q",
+                "1.10001.10.8.Q",
+                "3.10001.10.8.Q"
+            ]
+        );
+        assert_eq!(frame.height(), 3);
+
+        let change = frame.column("3.10001.10.8.Q")?.f64()?;
+        assert_eq!(change.get(0), Some(6.9));
+        assert_eq!(change.get(1), None);
+        assert_eq!(change.get(2), Some(5.4));
+
+        Ok(())
+    }
+
+    #[test]
+    fn wide_data_frames_reject_mixed_frequencies() -> Result<()> {
+        // 2023 alongside 2023-Q2 and 2023-Q3 would take the row of 2023-Q1.
+        let mut message: serde_json::Value = serde_json::from_slice(CPI_DATA)?;
+        let time_periods = message["data"]["structure"]["dimensions"]["observation"][0]["values"]
+            .as_array_mut()
+            .expect("time periods");
+        time_periods[0]["id"] = "2023".into();
+
+        let response = sdmx_json::parse_data(&serde_json::to_vec(&message)?)?;
+
+        assert!(matches!(
+            response.to_wide_data_frame(),
+            Err(ErrorCode::MixedFrequencies(..))
+        ));
+        assert!(response.to_data_frame().is_ok());
+
+        Ok(())
+    }
+}
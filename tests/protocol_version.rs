@@ -0,0 +1,234 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use abs_data::{
+        builders::{
+            dataflow_identifier_builder::DataflowIdentifierBuilder,
+            sdmx_client_builder::SdmxClientBuilder,
+        },
+        error_code::ErrorCode,
+        models::typed::{
+            dataflow_structure::DataflowStructure, datakey::DataKey,
+            datakey_dimension::DataKeyDimension, detail::Detail, period::Period,
+            protocol_version::ProtocolVersion, quarter::Quarter, reference::Reference,
+            structure_type::StructureType, version::Version,
+        },
+        result::Result,
+        transport::memory_transport::MemoryTransport,
+    };
+
+    const CPI_CONSTRAINTS_V3: &[u8] = include_bytes!("fixtures/cpi_constraints_v3.json");
+
+    #[test]
+    fn sdmx_3_0_data_urls() -> Result<()> {
+        let client = SdmxClientBuilder::new()
+            .protocol_version(ProtocolVersion::Sdmx30)
+            .build()?;
+
+        let unversioned = DataflowIdentifierBuilder::new("CPI").build();
+        let versioned = DataflowIdentifierBuilder::new("CPI")
+            .agency_id("ABS")
+            .version(&Version::new(1, 1, 0))
+            .build();
+
+        assert_eq!(
+            client.data_request(&unversioned).build()?.url(),
+            "https://api.data.abs.gov.au/data/dataflow/ABS/CPI/~/*"
+        );
+
+        let key = DataKey::parse("1.40066.10.8.Q")?;
+        let start = Period::YearQuarter(2020, Quarter::First);
+        let end = Period::YearQuarter(2022, Quarter::Fourth);
+
+        let request = client
+            .data_request(&versioned)
+            .data_key(&key)
+            .start_period(&start)
+            .end_period(&end)
+            .component_filter("OBS_VALUE", "ge:100")
+            .detail(&Detail::DataOnly)
+            .updated_after("2023-01-01T00:00:00Z")
+            .build()?;
+
+        assert_eq!(
+            request.url(),
+            "https://api.data.abs.gov.au/data/dataflow/ABS/CPI/1.1.0/1.40066.10.8.Q\
+             ?c[TIME_PERIOD]=ge:2020-Q1+le:2022-Q4&c[OBS_VALUE]=ge:100\
+             &attributes=none&measures=all&updatedAfter=2023-01-01T00:00:00Z"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sdmx_3_0_structure_urls() -> Result<()> {
+        let client = SdmxClientBuilder::new()
+            .protocol_version(ProtocolVersion::Sdmx30)
+            .build()?;
+
+        let version = Version::new(1, 1, 0);
+        let reference = Reference::StructureType(StructureType::ContentConstraint);
+
+        let request = client
+            .meta_request(&StructureType::DataFlow)
+            .structure_id("CPI")
+            .structure_version(&version)
+            .reference(&reference)
+            .build();
+
+        assert_eq!(
+            request.url(),
+            "https://api.data.abs.gov.au/structure/dataflow/ABS/CPI/1.1.0?references=dataconstraint"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn requests_can_override_client_protocol_version() -> Result<()> {
+        let client = SdmxClientBuilder::new()
+            .protocol_version(ProtocolVersion::Sdmx30)
+            .build()?;
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+        let start = Period::Year(2012);
+
+        let request = client
+            .data_request(&dataflow_identifier)
+            .start_period(&start)
+            .protocol_version(ProtocolVersion::Sdmx21)
+            .build()?;
+
+        assert_eq!(
+            request.url(),
+            "https://api.data.abs.gov.au/data/ABS,CPI/all?startPeriod=2012"
+        );
+
+        let request = client
+            .meta_request(&StructureType::CodeList)
+            .protocol_version(ProtocolVersion::Sdmx21)
+            .build();
+
+        assert_eq!(request.url(), "https://api.data.abs.gov.au/codelist/ABS");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn datakey_builder_reads_sdmx_3_0_data_constraints() -> Result<()> {
+        let transport = MemoryTransport::new().route(
+            "https://api.data.abs.gov.au/structure/dataflow/ABS/CPI?references=descendants",
+            CPI_CONSTRAINTS_V3,
+        );
+        let client = SdmxClientBuilder::new()
+            .protocol_version(ProtocolVersion::Sdmx30)
+            .transport(transport)
+            .build()?;
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+
+        let key = client
+            .datakey(&dataflow_identifier)
+            .add(&DataKeyDimension::new("MEASURE", "1"))
+            .add(&DataKeyDimension::new("INDEX", "40066"))
+            .add(&DataKeyDimension::new("REGION", "8"))
+            .add(&DataKeyDimension::new("FREQ", "Q"))
+            .add(&DataKeyDimension::new("TSEST", "10"))
+            .build()
+            .await?;
+
+        assert_eq!(key, DataKey::parse("1.40066.10.8.Q")?);
+
+        Ok(())
+    }
+
+    fn cpi_structure() -> DataflowStructure {
+        let dimensions = ["MEASURE", "INDEX", "TSEST", "REGION", "FREQ"];
+
+        DataflowStructure::new(
+            dimensions.into_iter().map(Into::into).collect(),
+            HashMap::new(),
+            HashMap::new(),
+        )
+    }
+
+    #[test]
+    fn sdmx_3_0_keys_use_wildcards_and_comma_separated_codes() -> Result<()> {
+        let client = SdmxClientBuilder::new()
+            .protocol_version(ProtocolVersion::Sdmx30)
+            .build()?;
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+
+        let wildcard = DataKey::parse("1.40066...Q")?;
+        let request = client
+            .data_request(&dataflow_identifier)
+            .data_key(&wildcard)
+            .build()?;
+
+        assert_eq!(
+            request.url(),
+            "https://api.data.abs.gov.au/data/dataflow/ABS/CPI/~/1.40066.*.*.Q"
+        );
+
+        let or = DataKey::parse("1.40066+10001.10.1+8.Q")?;
+        let request = client
+            .data_request(&dataflow_identifier)
+            .data_key(&or)
+            .build()?;
+
+        assert_eq!(
+            request.url(),
+            "https://api.data.abs.gov.au/data/dataflow/ABS/CPI/~/1.40066,10001.10.1,8.Q"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sdmx_2_1_component_filters_move_into_the_key() -> Result<()> {
+        let structure = cpi_structure();
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+        let key = DataKey::parse("1.40066...Q")?;
+        let client = SdmxClientBuilder::new().build()?;
+
+        let request = client
+            .data_request(&dataflow_identifier)
+            .data_key(&key)
+            .structure(&structure)
+            .component_filter("REGION", "1,8")
+            .build()?;
+
+        assert_eq!(
+            request.url(),
+            "https://api.data.abs.gov.au/data/ABS,CPI/1.40066..1+8.Q"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sdmx_2_1_rejects_component_filters_outside_the_key() -> Result<()> {
+        let client = SdmxClientBuilder::new().build()?;
+        let structure = cpi_structure();
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+
+        let without_structure = client
+            .data_request(&dataflow_identifier)
+            .component_filter("REGION", "1")
+            .build();
+
+        let not_a_dimension = client
+            .data_request(&dataflow_identifier)
+            .structure(&structure)
+            .component_filter("OBS_VALUE", "ge:100")
+            .build();
+
+        for result in [without_structure, not_a_dimension] {
+            assert!(matches!(
+                result,
+                Err(ErrorCode::UnsupportedComponentFilter(_))
+            ));
+        }
+
+        Ok(())
+    }
+}
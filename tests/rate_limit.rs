@@ -0,0 +1,234 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::{Duration, Instant},
+    };
+
+    use tokio::sync::Notify;
+
+    use abs_data::{
+        builders::{
+            dataflow_identifier_builder::DataflowIdentifierBuilder,
+            rate_limiter_builder::RateLimiterBuilder, sdmx_client_builder::SdmxClientBuilder,
+        },
+        error_code::ErrorCode,
+        models::typed::sdmx_client::SdmxClient,
+        result::Result,
+        transport::{
+            memory_transport::MemoryTransport, BodyStream, StreamingResponse, Transport,
+            TransportResponse,
+        },
+    };
+    use async_trait::async_trait;
+    use futures::future::join_all;
+
+    const CPI_DATA: &[u8] = include_bytes!("fixtures/cpi_data.json");
+    const CPI_URL: &str = "https://api.data.abs.gov.au/data/ABS,CPI/all";
+
+    #[derive(Debug, Default)]
+    struct SlowTransport {
+        in_flight: AtomicUsize,
+        max_in_flight: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Transport for SlowTransport {
+        async fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<TransportResponse> {
+            let current = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(current, Ordering::SeqCst);
+
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            let response = MemoryTransport::new()
+                .route(CPI_URL, CPI_DATA)
+                .get(url, headers)
+                .await;
+
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            response
+        }
+    }
+
+    /// Streams bodies that are only served once `release` is notified.
+    #[derive(Debug, Default)]
+    struct HeldTransport {
+        release: Arc<Notify>,
+    }
+
+    struct HeldBody {
+        release: Arc<Notify>,
+        body: Option<Box<[u8]>>,
+    }
+
+    #[async_trait]
+    impl BodyStream for HeldBody {
+        async fn chunk(&mut self) -> Result<Option<Box<[u8]>>> {
+            if self.body.is_some() {
+                self.release.notified().await;
+            }
+
+            Ok(self.body.take())
+        }
+    }
+
+    #[async_trait]
+    impl Transport for HeldTransport {
+        async fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<TransportResponse> {
+            MemoryTransport::new()
+                .route(CPI_URL, CPI_DATA)
+                .get(url, headers)
+                .await
+        }
+
+        async fn get_stream(
+            &self,
+            url: &str,
+            headers: &[(&str, &str)],
+        ) -> Result<StreamingResponse> {
+            let response = self.get(url, headers).await?;
+
+            Ok(StreamingResponse {
+                head: TransportResponse::new(response.status, Box::new([])),
+                body: Box::new(HeldBody {
+                    release: self.release.clone(),
+                    body: Some(response.body),
+                }),
+            })
+        }
+    }
+
+    async fn send_concurrently(client: &SdmxClient, count: usize) -> Result<()> {
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+        let requests = (0..count)
+            .map(|_| client.data_request(&dataflow_identifier).build())
+            .collect::<Result<Vec<_>>>()?;
+
+        join_all(requests.iter().map(|request| request.send()))
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn requests_are_spaced_by_rate() -> Result<()> {
+        let client = SdmxClientBuilder::new()
+            .transport(MemoryTransport::new().route(CPI_URL, CPI_DATA))
+            .rate_limiter(
+                RateLimiterBuilder::new()
+                    .requests_per_second(50.0)
+                    .burst(1)
+                    .build()?,
+            )
+            .build()?;
+
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+        let request = client.data_request(&dataflow_identifier).build()?;
+
+        let started = Instant::now();
+        let (a, b, c, d, e) = tokio::join!(
+            request.send(),
+            request.send(),
+            request.send(),
+            request.send(),
+            request.send()
+        );
+        a?;
+        b?;
+        c?;
+        d?;
+        e?;
+
+        assert!(started.elapsed() >= Duration::from_millis(80));
+
+        let metrics = client.rate_limiter().unwrap().metrics();
+        assert_eq!(metrics.requests, 5);
+        assert!(metrics.max_wait >= Duration::from_millis(70));
+        assert!(metrics.total_wait >= metrics.max_wait);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn limiter_is_shared_by_cloned_clients() -> Result<()> {
+        let client = SdmxClientBuilder::new()
+            .transport(MemoryTransport::new().route(CPI_URL, CPI_DATA))
+            .rate_limiter(
+                RateLimiterBuilder::new()
+                    .requests_per_second(1000.0)
+                    .build()?,
+            )
+            .build()?;
+        let clone = client.clone();
+
+        send_concurrently(&client, 2).await?;
+        send_concurrently(&clone, 3).await?;
+
+        assert_eq!(client.rate_limiter().unwrap().metrics().requests, 5);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn in_flight_requests_are_capped() -> Result<()> {
+        let transport = SlowTransport::default();
+        let max_in_flight = transport.max_in_flight.clone();
+
+        let client = SdmxClientBuilder::new()
+            .transport(transport)
+            .rate_limiter(RateLimiterBuilder::new().max_in_flight(2).build()?)
+            .build()?;
+
+        send_concurrently(&client, 8).await?;
+
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn streamed_requests_stay_in_flight_until_their_body_is_read() -> Result<()> {
+        let transport = HeldTransport::default();
+        let release = transport.release.clone();
+
+        let client = SdmxClientBuilder::new()
+            .transport(transport)
+            .rate_limiter(RateLimiterBuilder::new().max_in_flight(1).build()?)
+            .build()?;
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+        let request = client.data_request(&dataflow_identifier).build()?;
+
+        let mut stream = request.send_stream().await?;
+
+        let blocked = tokio::time::timeout(Duration::from_millis(50), request.send()).await;
+        assert!(blocked.is_err());
+
+        release.notify_one();
+        while stream.next().await.transpose()?.is_some() {}
+
+        let sent = tokio::time::timeout(Duration::from_secs(1), request.send()).await;
+        assert!(matches!(sent, Ok(Ok(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn rates_must_be_finite_and_positive() {
+        for rate in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let result = RateLimiterBuilder::new().requests_per_second(rate).build();
+            assert!(matches!(
+                result,
+                Err(ErrorCode::InvalidRequestsPerSecond(_))
+            ));
+        }
+
+        assert!(RateLimiterBuilder::new()
+            .requests_per_second(0.5)
+            .build()
+            .is_ok());
+    }
+}
@@ -0,0 +1,202 @@
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use abs_data::{
+        builders::{
+            dataflow_identifier_builder::DataflowIdentifierBuilder,
+            retry_policy_builder::RetryPolicyBuilder, sdmx_client_builder::SdmxClientBuilder,
+        },
+        error_code::ErrorCode,
+        models::typed::{retry_policy::parse_retry_after, sdmx_client::SdmxClient},
+        result::Result,
+    };
+    use reqwest::StatusCode;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const CPI_DATA: &[u8] = include_bytes!("fixtures/cpi_data.json");
+    const CPI_PATH: &str = "/data/ABS,CPI/all";
+
+    async fn script(server: &MockServer, statuses: &[u16]) {
+        for (i, status) in statuses.iter().enumerate() {
+            Mock::given(method("GET"))
+                .and(path(CPI_PATH))
+                .respond_with(ResponseTemplate::new(*status))
+                .up_to_n_times(1)
+                .with_priority(i as u8 + 1)
+                .mount(server)
+                .await;
+        }
+
+        Mock::given(method("GET"))
+            .and(path(CPI_PATH))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(CPI_DATA))
+            .with_priority(u8::MAX)
+            .mount(server)
+            .await;
+    }
+
+    fn client(server: &MockServer, max_attempts: u32) -> Result<SdmxClient> {
+        let retry_policy = RetryPolicyBuilder::new()
+            .max_attempts(max_attempts)
+            .initial_backoff(Duration::from_millis(1))
+            .max_backoff(Duration::from_millis(5))
+            .build();
+
+        SdmxClientBuilder::new()
+            .base_url(&server.uri())
+            .retry_policy(retry_policy)
+            .build()
+    }
+
+    async fn send(client: &SdmxClient) -> Result<()> {
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+        client
+            .data_request(&dataflow_identifier)
+            .build()?
+            .send()
+            .await?;
+        Ok(())
+    }
+
+    async fn received(server: &MockServer) -> usize {
+        server.received_requests().await.unwrap_or_default().len()
+    }
+
+    #[tokio::test]
+    async fn retries_transient_statuses_until_success() -> Result<()> {
+        let server = MockServer::start().await;
+        script(&server, &[503, 429, 502]).await;
+
+        send(&client(&server, 4)?).await?;
+
+        assert_eq!(received(&server).await, 4);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() -> Result<()> {
+        let server = MockServer::start().await;
+        script(&server, &[503, 504, 503]).await;
+
+        let result = send(&client(&server, 2)?).await;
+
+        assert!(matches!(
+            result,
+            Err(ErrorCode::Http(status)) if status == StatusCode::GATEWAY_TIMEOUT
+        ));
+        assert_eq!(received(&server).await, 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_non_retryable_status() -> Result<()> {
+        let server = MockServer::start().await;
+        script(&server, &[404]).await;
+
+        let result = send(&client(&server, 5)?).await;
+
+        assert!(matches!(
+            result,
+            Err(ErrorCode::Http(status)) if status == StatusCode::NOT_FOUND
+        ));
+        assert_eq!(received(&server).await, 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn default_client_does_not_retry() -> Result<()> {
+        let server = MockServer::start().await;
+        script(&server, &[503]).await;
+
+        let client = SdmxClientBuilder::new().base_url(&server.uri()).build()?;
+        let result = send(&client).await;
+
+        assert!(matches!(result, Err(ErrorCode::Http(_))));
+        assert_eq!(received(&server).await, 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn waits_for_retry_after() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path(CPI_PATH))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        script(&server, &[]).await;
+
+        let started = Instant::now();
+        send(&client(&server, 2)?).await?;
+
+        assert!(started.elapsed() >= Duration::from_secs(1));
+        assert_eq!(received(&server).await, 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn caps_retry_after() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path(CPI_PATH))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "86400"))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        script(&server, &[]).await;
+
+        let retry_policy = RetryPolicyBuilder::new()
+            .max_attempts(2)
+            .max_retry_after(Duration::from_millis(10))
+            .build();
+        let client = SdmxClientBuilder::new()
+            .base_url(&server.uri())
+            .retry_policy(retry_policy)
+            .build()?;
+
+        let started = Instant::now();
+        send(&client).await?;
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(received(&server).await, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn backoff_grows_exponentially_up_to_max() {
+        let policy = RetryPolicyBuilder::new()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(350))
+            .multiplier(2.0)
+            .build();
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn retry_after_accepts_seconds_and_dates() {
+        assert_eq!(parse_retry_after("7"), Some(Duration::from_secs(7)));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon"), None);
+    }
+}
@@ -0,0 +1,150 @@
+#[cfg(test)]
+mod tests {
+    use abs_data::{
+        builders::{
+            dataflow_identifier_builder::DataflowIdentifierBuilder,
+            sdmx_client_builder::SdmxClientBuilder,
+        },
+        error_code::ErrorCode,
+        models::typed::{
+            data_format::DataFormat, missing_value::MissingValue, protocol_version::ProtocolVersion,
+        },
+        parsers::{sdmx_csv, sdmx_json},
+        result::Result,
+        transport::memory_transport::MemoryTransport,
+    };
+
+    const CPI_DATA: &[u8] = include_bytes!("fixtures/cpi_data.json");
+    const CPI_DATA_CSV: &[u8] = include_bytes!("fixtures/cpi_data.csv");
+
+    const CPI_DATA_URL: &str = "https://api.data.abs.gov.au/data/ABS,CPI/all";
+
+    #[test]
+    fn rows_line_up_with_columns() -> Result<()> {
+        let csv = sdmx_csv::parse_rows(CPI_DATA_CSV)?;
+
+        assert_eq!(
+            csv.columns.dimensions.as_ref(),
+            ["MEASURE", "INDEX", "TSEST", "REGION", "FREQ"].map(Box::<str>::from)
+        );
+        assert_eq!(
+            csv.columns.attributes.as_ref(),
+            ["UNIT_MEASURE", "OBS_STATUS"].map(Box::<str>::from)
+        );
+        assert_eq!(csv.rows.len(), 8);
+
+        let row = &csv.rows[2];
+        let region = csv.columns.dimension_position("REGION").unwrap();
+        let status = csv.columns.attribute_position("OBS_STATUS").unwrap();
+
+        assert_eq!(row.dataflow, "ABS:CPI(1.1.0)".into());
+        assert_eq!(row.dimensions[region], "1".into());
+        assert_eq!(row.time_period.as_deref(), Some("2023-Q3"));
+        assert_eq!(row.obs_value.as_ref().map(AsRef::as_ref), Some("133.5"));
+        assert_eq!(row.attributes[status].as_deref(), Some("E"));
+        assert_eq!(csv.rows[0].attributes[status], None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn series_match_sdmx_json() -> Result<()> {
+        let json = sdmx_json::parse_data(CPI_DATA)?;
+        let csv = sdmx_csv::parse_data(CPI_DATA_CSV)?;
+
+        let json_series = &json.data.data_sets[0].series;
+        let csv_series = &csv.data.data_sets[0].series;
+
+        assert_eq!(json_series.len(), csv_series.len());
+
+        for (key, data) in json_series {
+            let csv_data = &csv_series[key];
+            for (time, values) in &data.observations {
+                assert_eq!(values[0], csv_data.observations[time][0]);
+            }
+        }
+
+        let structure = csv.data.structure.as_ref().unwrap();
+        assert_eq!(structure.dimensions.observation[0].id, "TIME_PERIOD".into());
+        assert_eq!(structure.attributes.observation.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reads_sdmx_csv_2_0_with_labels() -> Result<()> {
+        let body = "STRUCTURE,STRUCTURE_ID,ACTION,MEASURE: Measure,REGION: Region,TIME_PERIOD: Time Period,OBS_VALUE,UNIT_MEASURE: Unit of Measure\n\
+                    dataflow,ABS:CPI(1.1.0),I,1,8,2023-Q1,NaN,IN\n";
+
+        let csv = sdmx_csv::parse_rows(body.as_bytes())?;
+
+        assert_eq!(
+            csv.columns.dimensions.as_ref(),
+            ["MEASURE", "REGION"].map(Box::<str>::from)
+        );
+        assert_eq!(csv.rows[0].dataflow, "ABS:CPI(1.1.0)".into());
+        assert_eq!(
+            csv.rows[0]
+                .obs_value
+                .as_ref()
+                .and_then(|value| value.missing()),
+            Some(MissingValue::NotAvailable)
+        );
+        assert_eq!(csv.rows[0].attributes[0].as_deref(), Some("IN"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn csv_requests_accept_and_parse_sdmx_csv() -> Result<()> {
+        let transport = MemoryTransport::new().route(CPI_DATA_URL, CPI_DATA_CSV);
+        let client = SdmxClientBuilder::new().transport(transport).build()?;
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI")
+            .agency_id("ABS")
+            .build();
+
+        let request = client
+            .data_request(&dataflow_identifier)
+            .format(DataFormat::SdmxCsv)
+            .build()?;
+
+        assert_eq!(
+            request.headers(),
+            &[("Accept", "application/vnd.sdmx.data+csv;version=1.0.0")]
+        );
+        assert_eq!(request.send_rows().await?.rows.len(), 8);
+        assert_eq!(request.send().await?.data.data_sets[0].series.len(), 3);
+
+        let request = client
+            .data_request(&dataflow_identifier)
+            .format(DataFormat::SdmxCsv)
+            .protocol_version(ProtocolVersion::Sdmx30)
+            .build()?;
+
+        assert_eq!(
+            request.headers(),
+            &[("Accept", "application/vnd.sdmx.data+csv;version=2.0.0")]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rows_require_the_csv_format() -> Result<()> {
+        let transport = MemoryTransport::new().route(CPI_DATA_URL, CPI_DATA);
+        let client = SdmxClientBuilder::new().transport(transport).build()?;
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI")
+            .agency_id("ABS")
+            .build();
+
+        let result = client
+            .data_request(&dataflow_identifier)
+            .build()?
+            .send_rows()
+            .await;
+
+        assert!(matches!(result, Err(ErrorCode::UnsupportedDataMessage(_))));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::with_structures;
+    use abs_data::{
+        builders::{
+            dataflow_identifier_builder::DataflowIdentifierBuilder,
+            sdmx_client_builder::SdmxClientBuilder,
+        },
+        error_code::ErrorCode,
+        models::typed::{protocol_version::ProtocolVersion, sdmx_json_version::SdmxJsonVersion},
+        parsers::sdmx_json,
+        result::Result,
+        transport::memory_transport::MemoryTransport,
+    };
+
+    const CPI_DATA: &[u8] = include_bytes!("fixtures/cpi_data.json");
+    const CPI_DATA_V2: &[u8] = include_bytes!("fixtures/cpi_data_v2.json");
+
+    #[test]
+    fn detects_message_version_from_schema() -> Result<()> {
+        assert_eq!(sdmx_json::detect_version(CPI_DATA)?, SdmxJsonVersion::V1);
+        assert_eq!(sdmx_json::detect_version(CPI_DATA_V2)?, SdmxJsonVersion::V2);
+        assert_eq!(sdmx_json::detect_version(b"{}")?, SdmxJsonVersion::V1);
+
+        for (schema, version) in [
+            (
+                "https://example.org/schemas/2.0.0/data.json",
+                SdmxJsonVersion::V2,
+            ),
+            (
+                "https://example.org/schemas/1.0/data.json",
+                SdmxJsonVersion::V1,
+            ),
+            (
+                "https://example.org/v2.0/schemas/1.0/data.json",
+                SdmxJsonVersion::V1,
+            ),
+            ("https://example.org/12.0/data.json", SdmxJsonVersion::V1),
+            (
+                "https://example.org/2.0-draft/data.json",
+                SdmxJsonVersion::V1,
+            ),
+        ] {
+            assert_eq!(SdmxJsonVersion::from_schema(schema), version);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn both_versions_normalise_to_the_same_data() -> Result<()> {
+        let v1 = sdmx_json::parse_data(CPI_DATA)?;
+        let v2 = sdmx_json::parse_data(CPI_DATA_V2)?;
+
+        let v1_structure = v1.data.structure.as_ref().unwrap();
+        let v2_structure = v2.data.structure.as_ref().unwrap();
+
+        assert_eq!(v1_structure.dimensions, v2_structure.dimensions);
+        assert_eq!(
+            v1_structure.attributes.series[0].values,
+            v2_structure.attributes.series[0].values
+        );
+        assert_eq!(
+            v2_structure.measures.as_ref().unwrap().observation[0].id,
+            "OBS_VALUE".into()
+        );
+
+        assert_eq!(v1.data.data_sets[0].series, v2.data.data_sets[0].series);
+        assert_eq!(v2.data.data_sets[0].structure, Some(0));
+        assert_eq!(v1.meta.id, v2.meta.id);
+
+        Ok(())
+    }
+
+    #[test]
+    fn data_sets_resolve_their_own_structure() -> Result<()> {
+        let response = sdmx_json::parse_data(&with_structures(&[1, 1])?)?;
+
+        let structure = response.data.structure.as_ref().unwrap();
+        assert_eq!(structure.dimensions.series[0].id, "OTHER".into());
+        assert_eq!(response.data.data_sets.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn data_sets_with_different_structures_keep_their_own() -> Result<()> {
+        let bytes = with_structures(&[0, 1])?;
+        let response = sdmx_json::parse_data(&bytes)?;
+
+        let data = &response.data;
+        assert_eq!(data.structures.len(), 2);
+        assert_eq!(
+            data.structure_of(&data.data_sets[1])
+                .unwrap()
+                .dimensions
+                .series[0]
+                .id,
+            "OTHER".into()
+        );
+        assert!(matches!(
+            response.observations(),
+            Err(ErrorCode::UnsupportedDataMessage(_))
+        ));
+
+        let series = response.time_series()?;
+        let other = series
+            .iter()
+            .filter(|series| series.dimension("OTHER").is_some())
+            .count();
+        assert_eq!(other * 2, series.len());
+
+        let mut streamed = Vec::new();
+        sdmx_json::read_series(&bytes[..], |series| {
+            streamed.push(series);
+            Ok(())
+        })?;
+        assert_eq!(streamed.len(), series.len());
+        assert!(streamed.iter().all(|s| series.iter().any(|t| t == s)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn data_sets_referring_to_missing_structures_are_rejected() -> Result<()> {
+        let missing = sdmx_json::parse_data(&with_structures(&[2])?);
+
+        assert!(matches!(missing, Err(ErrorCode::UnsupportedDataMessage(_))));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sdmx_3_0_requests_accept_and_parse_sdmx_json_2_0() -> Result<()> {
+        let transport = MemoryTransport::new().route(
+            "https://api.data.abs.gov.au/data/dataflow/ABS/CPI/~/*",
+            CPI_DATA_V2,
+        );
+        let client = SdmxClientBuilder::new()
+            .protocol_version(ProtocolVersion::Sdmx30)
+            .transport(transport)
+            .build()?;
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+
+        let request = client.data_request(&dataflow_identifier).build()?;
+
+        assert_eq!(
+            request.headers(),
+            &[("Accept", "application/vnd.sdmx.data+json;version=2.0.0")]
+        );
+
+        let response = request.send().await?;
+
+        assert_eq!(response.data.data_sets[0].series.len(), 3);
+        assert!(response.data.structure.is_some());
+
+        Ok(())
+    }
+}
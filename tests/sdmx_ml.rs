@@ -0,0 +1,274 @@
+#[cfg(all(test, feature = "xml"))]
+mod tests {
+    use abs_data::{
+        builders::{
+            dataflow_identifier_builder::DataflowIdentifierBuilder,
+            sdmx_client_builder::SdmxClientBuilder,
+        },
+        error_code::ErrorCode,
+        models::{
+            derived::{meta_data_map::MetaDataMap, sdmx_response::SdmxResponse},
+            typed::{
+                data_format::DataFormat, dataflow_structure::DataflowStructure,
+                structure_format::StructureFormat, structure_type::StructureType,
+            },
+        },
+        parsers::{sdmx_json, sdmx_ml},
+        result::Result,
+        transport::memory_transport::MemoryTransport,
+    };
+
+    const CPI_CONSTRAINTS: &[u8] = include_bytes!("fixtures/cpi_constraints.json");
+    const CPI_DATA: &[u8] = include_bytes!("fixtures/cpi_data.json");
+    const CPI_DATA_STRUCTURE_SPECIFIC: &[u8] =
+        include_bytes!("fixtures/cpi_data_structure_specific.xml");
+    const CPI_DATA_GENERIC: &[u8] = include_bytes!("fixtures/cpi_data_generic.xml");
+
+    const CPI_STRUCTURE: &[u8] = include_bytes!("fixtures/cpi_structure.json");
+    const CPI_STRUCTURE_XML: &[u8] = include_bytes!("fixtures/cpi_structure.xml");
+
+    const CPI_DATA_URL: &str = "https://api.data.abs.gov.au/data/ABS,CPI/all";
+
+    fn dimensions() -> Box<[Box<str>]> {
+        ["MEASURE", "INDEX", "TSEST", "REGION", "FREQ", "TIME_PERIOD"]
+            .into_iter()
+            .map(Into::into)
+            .collect()
+    }
+
+    fn component_ids<'a>(ids: impl Iterator<Item = &'a Box<str>>) -> Vec<&'a str> {
+        ids.map(AsRef::as_ref).collect()
+    }
+
+    #[test]
+    fn structure_specific_data_matches_sdmx_json() -> Result<()> {
+        let json = sdmx_json::parse_data(CPI_DATA)?;
+        let xml = sdmx_ml::parse_data(CPI_DATA_STRUCTURE_SPECIFIC, Some(&dimensions()))?;
+
+        assert_eq!(json.data.data_sets[0].series, xml.data.data_sets[0].series);
+        assert_eq!(xml.data.data_sets[0].action, "Information".into());
+        assert_eq!(xml.meta.id, json.meta.id);
+        assert_eq!(xml.meta.sender.id, "ABS".into());
+
+        let structure = xml.data.structure.as_ref().unwrap();
+        assert_eq!(
+            component_ids(structure.dimensions.series.iter().map(|s| &s.id)),
+            ["MEASURE", "INDEX", "TSEST", "REGION", "FREQ"]
+        );
+        assert_eq!(structure.dimensions.observation[0].id, "TIME_PERIOD".into());
+        assert_eq!(structure.attributes.series[0].id, "UNIT_MEASURE".into());
+        assert_eq!(structure.attributes.observation[0].id, "OBS_STATUS".into());
+
+        Ok(())
+    }
+
+    #[test]
+    fn generic_data_matches_sdmx_json() -> Result<()> {
+        let json = sdmx_json::parse_data(CPI_DATA)?;
+        let xml = sdmx_ml::parse_data(CPI_DATA_GENERIC, None)?;
+
+        assert_eq!(json.data.data_sets[0].series, xml.data.data_sets[0].series);
+
+        let json_structure = json.data.structure.as_ref().unwrap();
+        let xml_structure = xml.data.structure.as_ref().unwrap();
+        for (json_series, xml_series) in json_structure
+            .dimensions
+            .series
+            .iter()
+            .zip(xml_structure.dimensions.series.iter())
+        {
+            assert_eq!(json_series.id, xml_series.id);
+            assert_eq!(json_series.key_position, xml_series.key_position);
+            assert_eq!(
+                component_ids(json_series.values.iter().map(|v| &v.id)),
+                component_ids(xml_series.values.iter().map(|v| &v.id))
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn structure_specific_data_without_dimensions_keys_on_every_series_attribute() -> Result<()> {
+        let xml = sdmx_ml::parse_data(CPI_DATA_STRUCTURE_SPECIFIC, None)?;
+
+        let structure = xml.data.structure.as_ref().unwrap();
+        assert_eq!(structure.dimensions.series.len(), 6);
+        assert!(structure.attributes.series.is_empty());
+        assert!(xml.data.data_sets[0].series.contains_key("0:0:0:0:0:0"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_messages_are_rejected() {
+        let result = sdmx_ml::parse_data(b"<message:Error><Text>oops</Text></message:Error>", None);
+
+        assert!(matches!(result, Err(ErrorCode::UnsupportedDataMessage(_))));
+    }
+
+    #[tokio::test]
+    async fn accept_header_follows_the_format() -> Result<()> {
+        let transport = MemoryTransport::new().route(CPI_DATA_URL, CPI_DATA_GENERIC);
+        let client = SdmxClientBuilder::new().transport(transport).build()?;
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI")
+            .agency_id("ABS")
+            .build();
+
+        let request = client
+            .data_request(&dataflow_identifier)
+            .format(DataFormat::GenericXml)
+            .build()?;
+
+        assert_eq!(
+            request.headers(),
+            &[("Accept", "application/vnd.sdmx.genericdata+xml;version=2.1")]
+        );
+        assert_eq!(request.send().await?.data.data_sets[0].series.len(), 3);
+
+        let request = client
+            .data_request(&dataflow_identifier)
+            .format(DataFormat::StructureSpecificXml)
+            .build()?;
+
+        assert_eq!(
+            request.headers(),
+            &[(
+                "Accept",
+                "application/vnd.sdmx.structurespecificdata+xml;version=2.1"
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn structure_specific_requests_fetch_the_structure_when_not_given() -> Result<()> {
+        let transport = MemoryTransport::new()
+            .route(CPI_DATA_URL, CPI_DATA_STRUCTURE_SPECIFIC)
+            .route(
+                "https://api.data.abs.gov.au/dataflow/ABS/CPI?references=descendants",
+                CPI_CONSTRAINTS,
+            );
+        let client = SdmxClientBuilder::new().transport(transport).build()?;
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI")
+            .agency_id("ABS")
+            .build();
+
+        let fetched = client
+            .data_request(&dataflow_identifier)
+            .format(DataFormat::StructureSpecificXml)
+            .build()?
+            .send()
+            .await?;
+
+        let structure = DataflowStructure::fetch(&client, &dataflow_identifier).await?;
+        let given = client
+            .data_request(&dataflow_identifier)
+            .format(DataFormat::StructureSpecificXml)
+            .structure(&structure)
+            .build()?
+            .send()
+            .await?;
+
+        assert_eq!(fetched, given);
+        assert!(fetched.data.data_sets[0].series.contains_key("1:0:0:1:0"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn structure_message_matches_sdmx_json() -> Result<()> {
+        let json = sdmx_json::deserialize::<SdmxResponse<MetaDataMap>>(CPI_STRUCTURE)?;
+        let xml = sdmx_ml::parse_structure(CPI_STRUCTURE_XML)?;
+
+        assert_eq!(json.data, xml.data);
+        assert_eq!(json.meta.id, xml.meta.id);
+        assert_eq!(json.meta.sender, xml.meta.sender);
+
+        Ok(())
+    }
+
+    #[test]
+    fn structure_message_round_trips_through_sdmx_json() -> Result<()> {
+        let xml = sdmx_ml::parse_structure(CPI_STRUCTURE_XML)?;
+
+        let json = serde_json::to_vec(&xml)?;
+        let round_tripped = sdmx_json::deserialize::<SdmxResponse<MetaDataMap>>(&json)?;
+
+        assert_eq!(xml, round_tripped);
+
+        Ok(())
+    }
+
+    #[test]
+    fn structure_message_resolves_references_to_urns() -> Result<()> {
+        let xml = sdmx_ml::parse_structure(CPI_STRUCTURE_XML)?;
+
+        let data_structure = &xml.data.data_structures.as_ref().unwrap()[0];
+        let components = data_structure.data_structure_components.as_ref().unwrap();
+        let region = &components.dimension_list.dimensions[1];
+
+        assert_eq!(region.position, Some(1));
+        assert_eq!(
+            region
+                .local_representation
+                .as_ref()
+                .and_then(|r| r.enumeration.as_deref()),
+            Some("urn:sdmx:org.sdmx.infomodel.codelist.Codelist=ABS:CL_CPI_REGION(1.0.0)")
+        );
+
+        let codes = xml.data.codelists.as_ref().unwrap()[1]
+            .codes
+            .as_ref()
+            .unwrap();
+        assert_eq!(codes[1].parent.as_deref(), Some("50"));
+
+        let categories = xml.data.category_schemes.as_ref().unwrap()[0]
+            .categories
+            .as_ref()
+            .unwrap();
+        assert_eq!(
+            categories[0].categories.as_ref().unwrap()[0].id,
+            "PRICES".into()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn data_messages_are_not_structure_messages() {
+        let result = sdmx_ml::parse_structure(CPI_DATA_GENERIC);
+
+        assert!(matches!(
+            result,
+            Err(ErrorCode::UnsupportedStructureMessage(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn structure_requests_accept_and_parse_sdmx_ml() -> Result<()> {
+        let transport = MemoryTransport::new().route(
+            "https://api.data.abs.gov.au/dataflow/ABS/CPI",
+            CPI_STRUCTURE_XML,
+        );
+        let client = SdmxClientBuilder::new().transport(transport).build()?;
+
+        let request = client
+            .meta_request(&StructureType::DataFlow)
+            .structure_id("CPI")
+            .format(StructureFormat::SdmxMl)
+            .build();
+
+        assert_eq!(
+            request.headers(),
+            &[("Accept", "application/vnd.sdmx.structure+xml;version=2.1")]
+        );
+
+        let response = request.send().await?;
+
+        assert_eq!(response.data.dataflows.unwrap()[0].id, "CPI".into());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+#[cfg(test)]
+mod tests {
+    use abs_data::{
+        builders::{
+            dataflow_identifier_builder::DataflowIdentifierBuilder,
+            sdmx_client_builder::SdmxClientBuilder,
+        },
+        error_code::ErrorCode,
+        models::typed::{
+            datakey::DataKey, datakey_dimension::DataKeyDimension,
+            structure_registry::StructureRegistry,
+        },
+        result::Result,
+    };
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const CPI_CONSTRAINTS: &[u8] = include_bytes!("fixtures/cpi_constraints.json");
+    const CPI_STRUCTURE: &[u8] = include_bytes!("fixtures/cpi_structure.json");
+
+    async fn server() -> MockServer {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/dataflow/ABS/CPI"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(CPI_CONSTRAINTS))
+            .mount(&server)
+            .await;
+
+        server
+    }
+
+    #[tokio::test]
+    async fn registry_fetches_structure_once() -> Result<()> {
+        let server = server().await;
+        let client = SdmxClientBuilder::new().base_url(&server.uri()).build()?;
+        let registry = StructureRegistry::new();
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+
+        for region in ["1", "8", "50"] {
+            let key = client
+                .datakey(&dataflow_identifier)
+                .registry(&registry)
+                .add(&DataKeyDimension::new("MEASURE", "1"))
+                .add(&DataKeyDimension::new("INDEX", "10001"))
+                .add(&DataKeyDimension::new("TSEST", "10"))
+                .add(&DataKeyDimension::new("REGION", region))
+                .add(&DataKeyDimension::new("FREQ", "Q"))
+                .build()
+                .await?;
+
+            assert_eq!(key, DataKey::parse(&format!("1.10001.10.{}.Q", region))?);
+        }
+
+        assert_eq!(registry.len(), 1);
+        assert_eq!(
+            server.received_requests().await.unwrap_or_default().len(),
+            1
+        );
+
+        let structure = registry.get(&client, &dataflow_identifier).unwrap();
+        assert_eq!(structure.position("REGION"), Some(3));
+        assert!(structure.codes("INDEX").unwrap().contains("40066"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_cached_is_synchronous_once_registered() -> Result<()> {
+        let server = server().await;
+        let client = SdmxClientBuilder::new().base_url(&server.uri()).build()?;
+        let registry = StructureRegistry::new();
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+
+        let missing = client
+            .datakey(&dataflow_identifier)
+            .registry(&registry)
+            .add(&DataKeyDimension::new("MEASURE", "1"))
+            .build_cached();
+
+        assert!(matches!(missing, Err(ErrorCode::StructureNotInRegistry(_))));
+
+        registry.get_or_fetch(&client, &dataflow_identifier).await?;
+
+        let key = client
+            .datakey(&dataflow_identifier)
+            .registry(&registry)
+            .add(&DataKeyDimension::new("MEASURE", "1"))
+            .add(&DataKeyDimension::new("INDEX", "40066"))
+            .add(&DataKeyDimension::new("REGION", "8"))
+            .add(&DataKeyDimension::new("FREQ", "Q"))
+            .add(&DataKeyDimension::new("TSEST", "10"))
+            .build_cached()?;
+
+        assert_eq!(key, DataKey::parse("1.40066.10.8.Q")?);
+
+        let invalid = client
+            .datakey(&dataflow_identifier)
+            .registry(&registry)
+            .add(&DataKeyDimension::new("REGION", "999"))
+            .build_cached();
+
+        assert!(matches!(
+            invalid,
+            Err(ErrorCode::DataKeyContainsInvalidDimensions(_))
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn registry_is_shared_between_clones() -> Result<()> {
+        let server = server().await;
+        let client = SdmxClientBuilder::new().base_url(&server.uri()).build()?;
+        let registry = StructureRegistry::new();
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+
+        let clone = registry.clone();
+        std::thread::spawn(move || assert!(clone.is_empty()))
+            .join()
+            .unwrap();
+
+        registry.get_or_fetch(&client, &dataflow_identifier).await?;
+
+        let clone = registry.clone();
+        std::thread::spawn(move || assert_eq!(clone.len(), 1))
+            .join()
+            .unwrap();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn registry_stores_codelists() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/dataflow/ABS/CPI"))
+            .and(query_param("references", "descendants"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(CPI_STRUCTURE))
+            .mount(&server)
+            .await;
+
+        let client = SdmxClientBuilder::new().base_url(&server.uri()).build()?;
+        let registry = StructureRegistry::new();
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+
+        let structure = registry.get_or_fetch(&client, &dataflow_identifier).await?;
+
+        assert_eq!(structure.label("MEASURE", "1"), Some("Index Numbers"));
+        assert_eq!(structure.all_codelists().len(), 3);
+        assert!(structure.codelist("REGION").is_some_and(|c| !c.is_empty()));
+        assert_eq!(structure.label("FREQ", "unknown"), None);
+
+        registry.get_or_fetch(&client, &dataflow_identifier).await?;
+
+        assert_eq!(
+            server.received_requests().await.unwrap_or_default().len(),
+            1
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn constraints_are_fetched_when_not_among_the_descendants() -> Result<()> {
+        let mut descendants: serde_json::Value = serde_json::from_slice(CPI_STRUCTURE)?;
+        descendants["data"]
+            .as_object_mut()
+            .unwrap()
+            .remove("contentConstraints");
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/dataflow/ABS/CPI"))
+            .and(query_param("references", "descendants"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(descendants))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/dataflow/ABS/CPI"))
+            .and(query_param("references", "contentconstraint"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(CPI_CONSTRAINTS))
+            .mount(&server)
+            .await;
+
+        let client = SdmxClientBuilder::new().base_url(&server.uri()).build()?;
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+
+        let structure = StructureRegistry::new()
+            .get_or_fetch(&client, &dataflow_identifier)
+            .await?;
+
+        assert!(structure.codes("INDEX").unwrap().contains("40066"));
+        assert_eq!(structure.label("MEASURE", "1"), Some("Index Numbers"));
+        assert_eq!(
+            server.received_requests().await.unwrap_or_default().len(),
+            2
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn registry_keeps_structures_apart_by_base_url() -> Result<()> {
+        let (first, second) = (server().await, server().await);
+        let first_client = SdmxClientBuilder::new().base_url(&first.uri()).build()?;
+        let second_client = SdmxClientBuilder::new().base_url(&second.uri()).build()?;
+        let registry = StructureRegistry::new();
+        let dataflow_identifier = DataflowIdentifierBuilder::new("CPI").build();
+
+        registry
+            .get_or_fetch(&first_client, &dataflow_identifier)
+            .await?;
+
+        assert!(registry.get(&second_client, &dataflow_identifier).is_none());
+
+        registry
+            .get_or_fetch(&second_client, &dataflow_identifier)
+            .await?;
+
+        assert_eq!(registry.len(), 2);
+        assert_eq!(
+            second.received_requests().await.unwrap_or_default().len(),
+            1
+        );
+
+        Ok(())
+    }
+}
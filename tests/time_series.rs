@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use abs_data::{
+        models::typed::{period::Period, quarter::Quarter},
+        parsers::sdmx_json,
+        result::Result,
+    };
+
+    const CPI_DATA: &[u8] = include_bytes!("fixtures/cpi_data.json");
+
+    #[test]
+    fn time_series_are_keyed_by_dimension_codes() -> Result<()> {
+        let response = sdmx_json::parse_data(CPI_DATA)?;
+
+        let time_series = response.time_series()?;
+
+        assert_eq!(time_series.len(), 3);
+
+        let keys = time_series
+            .iter()
+            .map(|series| series.key().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(keys, ["1.10001.10.1.Q", "1.10001.10.8.Q", "3.10001.10.8.Q"]);
+
+        let series = time_series
+            .find(&[("MEASURE", "1"), ("REGION", "8")])
+            .expect("series for MEASURE=1, REGION=8");
+
+        assert_eq!(series.key().as_ref(), "1.10001.10.8.Q");
+        assert_eq!(series.dimension("FREQ"), Some("Q"));
+        assert_eq!(series.attribute("UNIT_MEASURE"), Some("IN"));
+        assert_eq!(
+            series.values(),
+            [
+                (Period::YearQuarter(2023, Quarter::First), 129.0),
+                (Period::YearQuarter(2023, Quarter::Second), 130.0),
+                (Period::YearQuarter(2023, Quarter::Third), 131.9),
+            ]
+        );
+        assert_eq!(
+            series.observations()[1]
+                .attributes
+                .get("OBS_STATUS")
+                .map(AsRef::as_ref),
+            Some("R")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn time_series_filter_by_partial_codes() -> Result<()> {
+        let time_series = sdmx_json::parse_data(CPI_DATA)?.time_series()?;
+
+        assert_eq!(time_series.filter(&[("REGION", "8")]).count(), 2);
+        assert_eq!(time_series.filter(&[("MEASURE", "3")]).count(), 1);
+        assert!(time_series.find(&[("REGION", "2")]).is_none());
+
+        let series = time_series.get("3.10001.10.8.Q").expect("series");
+        assert_eq!(series.attribute("UNIT_MEASURE"), Some("PCT"));
+        assert_eq!(
+            series.values(),
+            [
+                (Period::YearQuarter(2023, Quarter::First), 6.9),
+                (Period::YearQuarter(2023, Quarter::Third), 5.4),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn time_series_yield_exact_decimal_values() -> Result<()> {
+        use std::str::FromStr;
+
+        use bigdecimal::BigDecimal;
+
+        let response = sdmx_json::parse_data(CPI_DATA)?;
+        let time_series = response.time_series()?;
+
+        let series = time_series
+            .find(&[("MEASURE", "3")])
+            .expect("series for MEASURE=3");
+        let decimal = |str| BigDecimal::from_str(str).expect("decimal");
+
+        assert_eq!(
+            series.decimal_values(),
+            [
+                (Period::YearQuarter(2023, Quarter::First), decimal("6.9")),
+                (Period::YearQuarter(2023, Quarter::Third), decimal("5.4")),
+            ]
+        );
+
+        let first = response.observations()?.next().expect("observation");
+        assert_eq!(first.decimal(), Some(decimal("130.8")));
+
+        Ok(())
+    }
+}